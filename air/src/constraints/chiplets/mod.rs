@@ -1,7 +1,7 @@
 use super::super::{
-    EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, Vec, CHIPLETS_OFFSET,
+    EvaluationFrame, Felt, FieldElement, String, TransitionConstraintDegree, Vec, CHIPLETS_OFFSET,
 };
-use crate::utils::{are_equal, binary_not, is_binary};
+use crate::utils::{are_equal, binary_not, constraint_names, is_binary};
 
 mod bitwise;
 mod hasher;
@@ -56,6 +56,25 @@ pub fn get_transition_constraint_count() -> usize {
         + memory::get_transition_constraint_count()
 }
 
+/// Returns the names of the transition constraints for the chiplets, in the order in which they
+/// are enforced.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    let mut names = constraint_names("chiplets.selectors", NUM_CONSTRAINTS);
+    names.append(&mut constraint_names(
+        "chiplets.hasher",
+        hasher::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names(
+        "chiplets.bitwise",
+        bitwise::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names(
+        "chiplets.memory",
+        memory::get_transition_constraint_count(),
+    ));
+    names
+}
+
 /// Enforces constraints for the chiplets module and all chiplet components.
 pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     frame: &EvaluationFrame<E>,
@@ -2,10 +2,13 @@ use crate::{
     chiplets::ChipletsFrameExt,
     constraints::MainFrameExt,
     trace::range::{B_RANGE_COL_IDX, M_COL_IDX, V_COL_IDX},
-    utils::are_equal,
+    utils::{are_equal, constraint_names},
     Assertion, EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree,
 };
-use vm_core::{utils::collections::Vec, ExtensionOf, ZERO};
+use vm_core::{
+    utils::{collections::Vec, string::String},
+    ExtensionOf, ZERO,
+};
 use winter_air::AuxTraceRandElements;
 
 // CONSTANTS
@@ -79,6 +82,11 @@ pub fn get_transition_constraint_count() -> usize {
     NUM_CONSTRAINTS
 }
 
+/// Returns the names of the transition constraints for the range checker.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    constraint_names("range", NUM_CONSTRAINTS)
+}
+
 /// Enforces constraints for the range checker.
 pub fn enforce_constraints<E: FieldElement>(frame: &EvaluationFrame<E>, result: &mut [E]) {
     // Constrain the transition of the value column between rows in the range checker table.
@@ -104,6 +112,11 @@ pub fn get_aux_transition_constraint_degrees() -> Vec<TransitionConstraintDegree
         .collect()
 }
 
+/// Returns the names of the transition constraints for the range checker's auxiliary columns.
+pub fn get_aux_transition_constraint_names() -> Vec<String> {
+    constraint_names("range.aux", NUM_AUX_CONSTRAINTS)
+}
+
 /// Enforces constraints on the range checker's auxiliary columns.
 pub fn enforce_aux_constraints<F, E>(
     main_frame: &EvaluationFrame<F>,
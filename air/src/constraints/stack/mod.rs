@@ -4,8 +4,12 @@ use super::super::{
     STACK_AUX_TRACE_OFFSET, STACK_TRACE_OFFSET, ZERO,
 };
 use crate::decoder::{IS_CALL_FLAG_COL_IDX, IS_SYSCALL_FLAG_COL_IDX, USER_OP_HELPERS_OFFSET};
-use crate::utils::{are_equal, is_binary};
-use vm_core::{stack::STACK_TOP_SIZE, utils::collections::Vec, StackOutputs, StarkField};
+use crate::utils::{are_equal, constraint_names, is_binary};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{collections::Vec, string::String},
+    StackOutputs, StarkField,
+};
 
 pub mod field_ops;
 pub mod io_ops;
@@ -88,6 +92,32 @@ pub fn get_transition_constraint_count() -> usize {
         + NUM_GENERAL_CONSTRAINTS
 }
 
+/// Returns the names of the transition constraints for the stack operations, in the order in which
+/// they are enforced.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    let mut names = constraint_names("stack.overflow", overflow::get_transition_constraint_count());
+    names.append(&mut constraint_names(
+        "stack.system_ops",
+        system_ops::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names(
+        "stack.field_ops",
+        field_ops::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names(
+        "stack.stack_manipulation",
+        stack_manipulation::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names(
+        "stack.u32_ops",
+        u32_ops::get_transition_constraint_count(),
+    ));
+    names.append(&mut constraint_names("stack.io_ops", io_ops::get_transition_constraint_count()));
+    names.append(&mut constraint_names("stack.general", NUM_GENERAL_CONSTRAINTS));
+
+    names
+}
+
 /// Enforces constraints for the stack module and all stack operations.
 pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     frame: &EvaluationFrame<E>,
@@ -1,5 +1,11 @@
 use super::String;
-use crate::trace::MIN_TRACE_LEN;
+use crate::{
+    options::{
+        MAX_BLOWUP_FACTOR, MAX_FRI_FOLDING_FACTOR, MAX_FRI_REMAINDER_DEGREE, MAX_GRINDING_FACTOR,
+        MAX_NUM_QUERIES, MIN_BLOWUP_FACTOR, MIN_FRI_FOLDING_FACTOR,
+    },
+    trace::MIN_TRACE_LEN,
+};
 use core::fmt::{Display, Formatter};
 
 // EXECUTION ERROR
@@ -30,3 +36,53 @@ impl Display for ExecutionOptionsError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for ExecutionOptionsError {}
+
+// PROVING OPTIONS ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvingOptionsError {
+    BlowupFactorTooSmall(usize, usize),
+    InconsistentWithProof,
+    InvalidBlowupFactor(usize),
+    InvalidFriFoldingFactor(usize),
+    InvalidFriRemainderMaxDegree(usize),
+    InvalidGrindingFactor(u32),
+    InvalidNumQueries(usize),
+}
+
+impl Display for ProvingOptionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        use ProvingOptionsError::*;
+
+        match self {
+            BlowupFactorTooSmall(min, actual) => {
+                write!(f, "The blowup factor must be at least {min} to evaluate the constraints of the VM, but was {actual}")
+            }
+            InconsistentWithProof => {
+                write!(f, "The hash function and the STARK protocol parameters of the proving options do not match the ones used to generate the proof")
+            }
+            InvalidBlowupFactor(blowup) => {
+                write!(f, "The blowup factor must be a power of two between {MIN_BLOWUP_FACTOR} and {MAX_BLOWUP_FACTOR}, but was {blowup}")
+            }
+            InvalidFriFoldingFactor(factor) => {
+                write!(f, "The FRI folding factor must be a power of two between {MIN_FRI_FOLDING_FACTOR} and {MAX_FRI_FOLDING_FACTOR}, but was {factor}")
+            }
+            InvalidFriRemainderMaxDegree(degree) => {
+                write!(f, "The FRI remainder max degree must be one less than a power of two and at most {MAX_FRI_REMAINDER_DEGREE}, but was {degree}")
+            }
+            InvalidGrindingFactor(factor) => {
+                write!(
+                    f,
+                    "The grinding factor must be at most {MAX_GRINDING_FACTOR}, but was {factor}"
+                )
+            }
+            InvalidNumQueries(num_queries) => {
+                write!(f, "The number of queries must be between 1 and {MAX_NUM_QUERIES}, but was {num_queries}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProvingOptionsError {}
@@ -30,9 +30,13 @@ use utils::TransitionConstraintRange;
 // EXPORTS
 // ================================================================================================
 
-pub use errors::ExecutionOptionsError;
-pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use errors::{ExecutionOptionsError, ProvingOptionsError};
+pub use options::{
+    ExecutionOptions, ProvingOptions, MAX_BLOWUP_FACTOR, MAX_FRI_FOLDING_FACTOR,
+    MAX_FRI_REMAINDER_DEGREE, MAX_GRINDING_FACTOR, MAX_NUM_QUERIES, MIN_BLOWUP_FACTOR,
+    MIN_FRI_FOLDING_FACTOR,
+};
+pub use proof::{ExecutionProof, HashFunction, ProofBundle};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -55,6 +59,36 @@ impl ProcessorAir {
     pub fn last_step(&self) -> usize {
         self.trace_length() - self.context().num_transition_exemptions()
     }
+
+    /// Returns the names of the transition constraints of the main trace segment, in the order in
+    /// which they are evaluated.
+    pub fn get_transition_constraint_names() -> Vec<String> {
+        let mut names = vec![String::from("system.clk")];
+        names.append(&mut stack::get_transition_constraint_names());
+        names.append(&mut range::get_transition_constraint_names());
+        names.append(&mut chiplets::get_transition_constraint_names());
+        names
+    }
+
+    /// Returns the names of the transition constraints of the auxiliary trace segment, in the order
+    /// in which they are evaluated.
+    pub fn get_aux_transition_constraint_names() -> Vec<String> {
+        range::get_aux_transition_constraint_names()
+    }
+
+    /// Returns the smallest blowup factor which can be used to generate proofs for this AIR.
+    ///
+    /// This is the blowup factor needed to evaluate the transition constraint of the highest
+    /// degree.
+    pub fn min_blowup_factor() -> usize {
+        let mut degrees = vec![TransitionConstraintDegree::new(1)];
+        degrees.append(&mut stack::get_transition_constraint_degrees());
+        degrees.append(&mut range::get_transition_constraint_degrees());
+        degrees.append(&mut range::get_aux_transition_constraint_degrees());
+        degrees.append(&mut chiplets::get_transition_constraint_degrees());
+
+        degrees.iter().map(|degree| degree.min_blowup_factor()).max().unwrap_or(1)
+    }
 }
 
 impl Air for ProcessorAir {
@@ -1,16 +1,85 @@
-use super::{ExecutionOptionsError, HashFunction};
-use crate::trace::MIN_TRACE_LEN;
+use super::{ExecutionOptionsError, HashFunction, ProcessorAir, ProvingOptionsError};
+use crate::trace::{AUX_TRACE_WIDTH, MIN_TRACE_LEN, TRACE_WIDTH};
+use core::cmp;
+use vm_core::{
+    chiplets::hasher::Digest,
+    utils::{
+        collections::BTreeMap, string::ToString, ByteReader, ByteWriter, Deserializable,
+        DeserializationError, Serializable,
+    },
+    Felt, FieldElement, StarkField,
+};
 use winter_air::{FieldExtension, ProofOptions as WinterProofOptions};
 
+// CONSTANTS
+// ================================================================================================
+
+// Bounds on the STARK protocol parameters accepted by [ProvingOptions::try_new()].
+
+/// Maximum number of queries.
+pub const MAX_NUM_QUERIES: usize = 255;
+/// Minimum blowup factor; the blowup factor must also be at least
+/// [ProcessorAir::min_blowup_factor()].
+pub const MIN_BLOWUP_FACTOR: usize = WinterProofOptions::MIN_BLOWUP_FACTOR;
+/// Maximum blowup factor.
+pub const MAX_BLOWUP_FACTOR: usize = 128;
+/// Maximum grinding factor.
+pub const MAX_GRINDING_FACTOR: u32 = 32;
+/// Minimum FRI folding factor.
+pub const MIN_FRI_FOLDING_FACTOR: usize = 2;
+/// Maximum FRI folding factor.
+pub const MAX_FRI_FOLDING_FACTOR: usize = 16;
+/// Maximum degree of the FRI remainder polynomial.
+pub const MAX_FRI_REMAINDER_DEGREE: usize = 255;
+
+/// Query security level above which the grinding factor contributes to the security of a proof;
+/// this is the same floor as the one used by winterfell to compute conjectured security.
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+// Sizes (in bytes) of the parts of a serialized proof which do not depend on the proving options;
+// these follow the serialization of `StarkProof` in winterfell.
+
+/// Identifier of the hash function, which [ExecutionProof](crate::ExecutionProof) prepends to the
+/// STARK proof.
+const HASH_FN_ID_SIZE: usize = 1;
+
+/// Proof context: the trace layout (main trace width, auxiliary trace width and number of
+/// auxiliary random elements), the log2 of the trace length, the length of the (empty) trace
+/// metadata, the field modulus with its length, and the six STARK protocol parameters.
+const PROOF_CONTEXT_SIZE: usize = 3 + 1 + 2 + (1 + Felt::ELEMENT_BYTES) + 6;
+
+/// Lengths of the commitments (u16), of the OOD trace states and constraint evaluations (u16
+/// each), and of the FRI proof: the number of layers (u8), the remainder length (u16), and the
+/// number of partitions (u8).
+const LENGTH_PREFIXES_SIZE: usize = 2 + 2 * 2 + (1 + 2 + 1);
+
+/// Proof-of-work nonce (u64).
+const POW_NONCE_SIZE: usize = 8;
+
+/// Fixed number of bytes in a serialized proof, independent of the proving options.
+const PROOF_SIZE_OVERHEAD: usize =
+    HASH_FN_ID_SIZE + PROOF_CONTEXT_SIZE + LENGTH_PREFIXES_SIZE + POW_NONCE_SIZE;
+
+/// Fixed number of bytes in a serialized set of queries (trace, constraint, or FRI layer queries):
+/// the lengths of the values and of the Merkle paths (u32 each), and the number of node vectors
+/// in the batch Merkle proof (u8).
+const QUERY_SIZE_OVERHEAD: usize = 2 * 4 + 1;
+
 // PROVING OPTIONS
 // ================================================================================================
 
 /// A set of parameters specifying how Miden VM execution proofs are to be generated.
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// Deterministic and low-memory proof generation affect only how a proof is computed and not the
+/// proof itself; thus, they are neither serialized nor taken into account when comparing two
+/// [ProvingOptions].
+#[derive(Debug, Clone, Eq)]
 pub struct ProvingOptions {
     pub exec_options: ExecutionOptions,
     pub proof_options: WinterProofOptions,
     pub hash_fn: HashFunction,
+    deterministic: bool,
+    low_memory: bool,
 }
 
 impl ProvingOptions {
@@ -40,9 +109,73 @@ impl ProvingOptions {
             exec_options,
             proof_options,
             hash_fn,
+            deterministic: false,
+            low_memory: false,
         }
     }
 
+    /// Creates a new instance of [ProvingOptions] from the specified parameters, validating them
+    /// first.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `num_queries` is zero or greater than 255.
+    /// - `blowup_factor` is not a power of two between 2 and 128, or is smaller than the blowup
+    ///   factor needed to evaluate the constraints of the VM.
+    /// - `grinding_factor` is greater than 32.
+    /// - `fri_folding_factor` is not a power of two between 2 and 16.
+    /// - `fri_remainder_max_degree` is not one less than a power of two, or is greater than 255.
+    pub fn try_new(
+        num_queries: usize,
+        blowup_factor: usize,
+        grinding_factor: u32,
+        field_extension: FieldExtension,
+        fri_folding_factor: usize,
+        fri_remainder_max_degree: usize,
+        hash_fn: HashFunction,
+    ) -> Result<Self, ProvingOptionsError> {
+        if num_queries == 0 || num_queries > MAX_NUM_QUERIES {
+            return Err(ProvingOptionsError::InvalidNumQueries(num_queries));
+        }
+        if !blowup_factor.is_power_of_two()
+            || !(MIN_BLOWUP_FACTOR..=MAX_BLOWUP_FACTOR).contains(&blowup_factor)
+        {
+            return Err(ProvingOptionsError::InvalidBlowupFactor(blowup_factor));
+        }
+        let min_blowup_factor = ProcessorAir::min_blowup_factor();
+        if blowup_factor < min_blowup_factor {
+            return Err(ProvingOptionsError::BlowupFactorTooSmall(
+                min_blowup_factor,
+                blowup_factor,
+            ));
+        }
+        if grinding_factor > MAX_GRINDING_FACTOR {
+            return Err(ProvingOptionsError::InvalidGrindingFactor(grinding_factor));
+        }
+        if !fri_folding_factor.is_power_of_two()
+            || !(MIN_FRI_FOLDING_FACTOR..=MAX_FRI_FOLDING_FACTOR).contains(&fri_folding_factor)
+        {
+            return Err(ProvingOptionsError::InvalidFriFoldingFactor(fri_folding_factor));
+        }
+        if !(fri_remainder_max_degree + 1).is_power_of_two()
+            || fri_remainder_max_degree > MAX_FRI_REMAINDER_DEGREE
+        {
+            return Err(ProvingOptionsError::InvalidFriRemainderMaxDegree(
+                fri_remainder_max_degree,
+            ));
+        }
+
+        Ok(Self::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            hash_fn,
+        ))
+    }
+
     /// Creates a new preset instance of [ProvingOptions] targeting 96-bit security level.
     ///
     /// If `recursive` flag is set to true, proofs will be generated using an arithmetization-
@@ -55,6 +188,8 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options,
                 hash_fn: HashFunction::Rpo256,
+                deterministic: false,
+                low_memory: false,
             }
         } else {
             let proof_options =
@@ -63,6 +198,8 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options,
                 hash_fn: HashFunction::Blake3_192,
+                deterministic: false,
+                low_memory: false,
             }
         }
     }
@@ -79,6 +216,8 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options,
                 hash_fn: HashFunction::Rpo256,
+                deterministic: false,
+                low_memory: false,
             }
         } else {
             let proof_options = WinterProofOptions::new(27, 16, 21, FieldExtension::Cubic, 8, 255);
@@ -86,10 +225,56 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options,
                 hash_fn: HashFunction::Blake3_256,
+                deterministic: false,
+                low_memory: false,
             }
         }
     }
 
+    /// Creates a new preset instance of [ProvingOptions] targeting 96-bit security level and
+    /// optimized for proof size rather than for prover time.
+    ///
+    /// Compared to [ProvingOptions::with_96_bit_security()], proofs generated with these options
+    /// use a higher blowup factor, more grinding, and a larger FRI folding factor. For traces of
+    /// 2^16 rows, `miden param-search` estimates proofs to be about 30% smaller and proof
+    /// generation to take about 2.5 times more work; these are model estimates rather than
+    /// measurements, and the actual figures depend on the trace length and on the hardware.
+    ///
+    /// Proofs are generated using BLAKE3 hash function; these proofs cannot be verified by the STARK
+    /// verifier in the standard library, which requires a blowup factor of 8.
+    pub fn with_size_optimized_96_bit_security() -> Self {
+        let proof_options = WinterProofOptions::new(16, 32, 20, FieldExtension::Quadratic, 16, 255);
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options,
+            hash_fn: HashFunction::Blake3_192,
+            deterministic: false,
+            low_memory: false,
+        }
+    }
+
+    /// Creates a new preset instance of [ProvingOptions] targeting 128-bit security level and
+    /// optimized for proof size rather than for prover time.
+    ///
+    /// Compared to [ProvingOptions::with_128_bit_security()], proofs generated with these options
+    /// use a higher blowup factor, more grinding, and a larger FRI folding factor. For traces of
+    /// 2^16 rows, `miden param-search` estimates proofs to be about 15% smaller and proof
+    /// generation to take about 1.7 times more work; these are model estimates rather than
+    /// measurements, and the actual figures depend on the trace length and on the hardware.
+    ///
+    /// Proofs are generated using BLAKE3 hash function; these proofs cannot be verified by the STARK
+    /// verifier in the standard library, which requires a blowup factor of 8.
+    pub fn with_size_optimized_128_bit_security() -> Self {
+        let proof_options = WinterProofOptions::new(21, 32, 24, FieldExtension::Cubic, 16, 255);
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options,
+            hash_fn: HashFunction::Blake3_256,
+            deterministic: false,
+            low_memory: false,
+        }
+    }
+
     /// Sets [ExecutionOptions] for this [ProvingOptions].
     ///
     /// This sets the maximum number of cycles a program is allowed to execute as well as
@@ -99,6 +284,38 @@ impl ProvingOptions {
         self
     }
 
+    /// Enables deterministic proof generation for this [ProvingOptions].
+    ///
+    /// Proofs are always fully determined by the program, its inputs, and the proving options,
+    /// with one exception: when the prover is compiled with the `concurrent` feature, the
+    /// proof-of-work nonce for query seed grinding is searched for in parallel, and thus, the
+    /// nonce included in the proof may differ between runs. With deterministic proof generation
+    /// enabled, this search is performed sequentially, and proving the same program against the
+    /// same inputs with the same options always produces byte-for-byte identical proofs.
+    ///
+    /// Deterministic proof generation is intended for testing and for caching of proofs. When the
+    /// `concurrent` feature is enabled, it makes proof generation single-threaded.
+    pub fn with_deterministic_proving(mut self) -> Self {
+        self.deterministic = true;
+        self
+    }
+
+    /// Enables low-memory proof generation for this [ProvingOptions].
+    ///
+    /// By default, the prover keeps the low-degree extensions (LDEs) of the execution trace and of
+    /// the constraint composition polynomial in memory for the duration of proof generation; for
+    /// long traces, these take up several times more memory than the trace itself. With
+    /// low-memory proof generation enabled, the LDEs are computed one coset of the trace domain at
+    /// a time, trace data is released as soon as it has been committed to, and the values needed
+    /// in the query phase are recomputed from the trace polynomials. This reduces peak memory
+    /// usage of the prover at the expense of longer proof generation.
+    ///
+    /// The generated proofs are identical to the proofs generated without this option.
+    pub fn with_low_memory_proving(mut self) -> Self {
+        self.low_memory = true;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -111,6 +328,97 @@ impl ProvingOptions {
     pub const fn execution_options(&self) -> &ExecutionOptions {
         &self.exec_options
     }
+
+    /// Returns true if deterministic proof generation is enabled for this [ProvingOptions].
+    pub const fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Returns true if low-memory proof generation is enabled for this [ProvingOptions].
+    pub const fn is_low_memory(&self) -> bool {
+        self.low_memory
+    }
+
+    /// Returns the conjectured security level (in bits) of proofs generated with these options
+    /// for an execution trace of the specified length.
+    ///
+    /// The security level does not increase with the length of the trace. Thus, the security level
+    /// for the maximum trace length bounds the security level of all proofs generated with these
+    /// options. For a given proof, the returned value is the same as the one returned by
+    /// [ExecutionProof::security_level()](crate::ExecutionProof::security_level).
+    ///
+    /// The computation mirrors winterfell's conjectured security of a `StarkProof`, which is not
+    /// exposed without a proof; integration tests compare the two for a range of options.
+    pub fn security_level(&self, trace_len: usize) -> u32 {
+        let options = &self.proof_options;
+
+        // compute max security we can get for a given field size
+        let field_size = Felt::MODULUS_BITS * options.field_extension().degree();
+        let field_security = field_size - trace_len.next_power_of_two().ilog2();
+
+        // compute security we get by executing multiple query rounds
+        let security_per_query = options.blowup_factor().ilog2();
+        let mut query_security = security_per_query * options.num_queries() as u32;
+        if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+            query_security += options.grinding_factor();
+        }
+
+        cmp::min(
+            cmp::min(field_security, query_security).saturating_sub(1),
+            self.hash_fn.collision_resistance(),
+        )
+    }
+
+    /// Returns an estimate of the size (in bytes) of proofs generated with these options for an
+    /// execution trace of the specified length.
+    ///
+    /// The size of a proof depends on how many of the queried positions coincide, and on how many
+    /// nodes the Merkle authentication paths of these positions share; the estimate uses the
+    /// expected values of these numbers for uniformly random positions.
+    pub fn estimate_proof_size(&self, trace_len: usize) -> usize {
+        let options = &self.proof_options;
+        let fri_options = options.to_fri_options();
+        let num_queries = options.num_queries();
+        let digest_size = self.hash_fn.digest_size();
+        let base_size = Felt::ELEMENT_BYTES;
+        let ext_size = base_size * options.field_extension().degree() as usize;
+        let num_composition_columns = ProcessorAir::min_blowup_factor();
+
+        let trace_len = cmp::max(trace_len, MIN_TRACE_LEN).next_power_of_two();
+        let lde_domain_size = trace_len * options.blowup_factor();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+
+        // main trace, auxiliary trace, constraint evaluations, FRI layers and FRI remainder
+        let commitments = (num_fri_layers + 4) * digest_size;
+
+        // queried rows of the main and auxiliary traces and of the constraint evaluations, each
+        // authenticated against its own Merkle tree
+        let num_rows = expected_distinct(num_queries, lde_domain_size);
+        let row_size = TRACE_WIDTH * base_size
+            + AUX_TRACE_WIDTH * ext_size
+            + num_composition_columns * ext_size;
+        let queries = num_rows * row_size as f64
+            + 3.0 * batch_proof_size(num_queries, lde_domain_size, digest_size);
+
+        // current and next rows of the trace, and the constraint composition columns
+        let ood_frame = (2 * (TRACE_WIDTH + AUX_TRACE_WIDTH) + num_composition_columns) * ext_size;
+
+        // each FRI layer reveals the cosets of the queried positions, and the remainder is sent
+        // as a polynomial in coefficient form
+        let folding_factor = fri_options.folding_factor();
+        let mut fri_proof = 0.0;
+        let mut domain_size = lde_domain_size;
+        for _ in 0..num_fri_layers {
+            domain_size /= folding_factor;
+            let num_cosets = expected_distinct(num_queries, domain_size);
+            fri_proof += num_cosets * (folding_factor * ext_size) as f64
+                + batch_proof_size(num_queries, domain_size, digest_size);
+        }
+        let remainder = domain_size / options.blowup_factor() * ext_size;
+
+        let size = queries + fri_proof;
+        (size + 0.5) as usize + commitments + ood_frame + remainder + PROOF_SIZE_OVERHEAD
+    }
 }
 
 impl Default for ProvingOptions {
@@ -125,6 +433,71 @@ impl From<ProvingOptions> for WinterProofOptions {
     }
 }
 
+impl PartialEq for ProvingOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.exec_options == other.exec_options
+            && self.proof_options == other.proof_options
+            && self.hash_fn == other.hash_fn
+    }
+}
+
+impl Serializable for ProvingOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.exec_options.write_into(target);
+        self.proof_options.write_into(target);
+        target.write_u8(self.hash_fn as u8);
+    }
+}
+
+impl Deserializable for ProvingOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let exec_options = source.read()?;
+        let proof_options = source.read()?;
+        let hash_fn = HashFunction::try_from(source.read_u8()?)?;
+        Ok(Self {
+            exec_options,
+            proof_options,
+            hash_fn,
+            deterministic: false,
+            low_memory: false,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the expected number of distinct values among `num_samples` values drawn uniformly at
+/// random from a domain of the specified size.
+fn expected_distinct(num_samples: usize, domain_size: usize) -> f64 {
+    let domain_size = domain_size as f64;
+    let mut miss_probability = 1.0;
+    for _ in 0..num_samples {
+        miss_probability *= 1.0 - 1.0 / domain_size;
+    }
+    domain_size * (1.0 - miss_probability)
+}
+
+/// Returns the expected size (in bytes) of a serialized batch Merkle proof for `num_leaves`
+/// leaves drawn uniformly at random from a tree with `tree_width` leaves, together with the
+/// length prefixes of the serialized query.
+///
+/// At every level of the tree, the proof needs to include the siblings of the nodes on the paths
+/// of the opened leaves, except for the siblings which are themselves on such paths. In addition,
+/// the number of nodes is recorded for every opened leaf.
+fn batch_proof_size(num_leaves: usize, tree_width: usize, digest_size: usize) -> f64 {
+    let mut num_path_nodes = 0.0;
+    let mut width = tree_width;
+    while width > 1 {
+        let num_nodes = expected_distinct(num_leaves, width);
+        let num_parents = expected_distinct(num_leaves, width / 2);
+        num_path_nodes += 2.0 * num_parents - num_nodes;
+        width /= 2;
+    }
+    let num_node_vectors = expected_distinct(num_leaves, tree_width);
+    num_path_nodes * digest_size as f64 + num_node_vectors + QUERY_SIZE_OVERHEAD as f64
+}
+
 // EXECUTION OPTIONS
 // ================================================================================================
 
@@ -132,10 +505,17 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// - `max_call_cycles` specifies the maximum number of cycles each CALL or SYSCALL block is
+///   allowed to execute, or None if the execution of such blocks is bounded only by `max_cycles`.
+/// - `call_cycle_limits` specifies the maximum numbers of cycles CALL and SYSCALL blocks are
+///   allowed to execute for specific callees, keyed by MAST roots of the callees; these limits
+///   take precedence over `max_call_cycles`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
+    max_call_cycles: Option<u32>,
+    call_cycle_limits: BTreeMap<Digest, u32>,
 }
 
 impl Default for ExecutionOptions {
@@ -143,6 +523,8 @@ impl Default for ExecutionOptions {
         ExecutionOptions {
             max_cycles: u32::MAX,
             expected_cycles: MIN_TRACE_LEN as u32,
+            max_call_cycles: None,
+            call_cycle_limits: BTreeMap::new(),
         }
     }
 }
@@ -173,9 +555,33 @@ impl ExecutionOptions {
         Ok(ExecutionOptions {
             max_cycles,
             expected_cycles,
+            max_call_cycles: None,
+            call_cycle_limits: BTreeMap::new(),
         })
     }
 
+    /// Sets the maximum number of cycles each CALL or SYSCALL block is allowed to execute.
+    ///
+    /// The budget applies separately to every execution context initiated by a CALL or a SYSCALL,
+    /// and covers all cycles executed from the start to the end of the block, including cycles
+    /// executed by nested calls.
+    pub fn with_max_call_cycles(mut self, max_call_cycles: u32) -> Self {
+        self.max_call_cycles = Some(max_call_cycles);
+        self
+    }
+
+    /// Sets the maximum number of cycles each CALL or SYSCALL block targeting the procedure with
+    /// the specified MAST root is allowed to execute.
+    ///
+    /// This limit takes precedence over the one set via [ExecutionOptions::with_max_call_cycles()]
+    /// for calls to this procedure, and thus can be used to give untrusted callees a tighter
+    /// budget than trusted ones. For dynamic calls, the limit of the procedure which is the target
+    /// of the call applies.
+    pub fn with_call_cycle_limit(mut self, callee: Digest, max_cycles: u32) -> Self {
+        self.call_cycle_limits.insert(callee, max_cycles);
+        self
+    }
+
     /// Returns maximum number of cycles
     pub fn max_cycles(&self) -> u32 {
         self.max_cycles
@@ -185,4 +591,57 @@ impl ExecutionOptions {
     pub fn expected_cycles(&self) -> u32 {
         self.expected_cycles
     }
+
+    /// Returns maximum number of cycles of a single CALL or SYSCALL block, if one was specified
+    pub fn max_call_cycles(&self) -> Option<u32> {
+        self.max_call_cycles
+    }
+
+    /// Returns maximum number of cycles of a single CALL or SYSCALL block targeting the procedure
+    /// with the specified MAST root, if one was specified either for this procedure or for all
+    /// CALL and SYSCALL blocks
+    pub fn call_cycle_limit(&self, callee: &Digest) -> Option<u32> {
+        self.call_cycle_limits.get(callee).copied().or(self.max_call_cycles)
+    }
+
+    /// Returns true if the number of cycles of CALL or SYSCALL blocks is bounded for at least
+    /// some callees
+    pub fn has_call_cycle_limits(&self) -> bool {
+        self.max_call_cycles.is_some() || !self.call_cycle_limits.is_empty()
+    }
+}
+
+impl Serializable for ExecutionOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.max_cycles);
+        target.write_u32(self.expected_cycles);
+        target.write_bool(self.max_call_cycles.is_some());
+        if let Some(max_call_cycles) = self.max_call_cycles {
+            target.write_u32(max_call_cycles);
+        }
+        target.write_u32(self.call_cycle_limits.len() as u32);
+        for (callee, max_cycles) in self.call_cycle_limits.iter() {
+            callee.write_into(target);
+            target.write_u32(*max_cycles);
+        }
+    }
+}
+
+impl Deserializable for ExecutionOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let max_cycles = source.read_u32()?;
+        let expected_cycles = source.read_u32()?;
+        let mut options = Self::new(Some(max_cycles), expected_cycles)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        if source.read_bool()? {
+            options = options.with_max_call_cycles(source.read_u32()?);
+        }
+        let num_call_cycle_limits = source.read_u32()?;
+        for _ in 0..num_call_cycle_limits {
+            let callee = source.read::<Digest>()?;
+            options = options.with_call_cycle_limit(callee, source.read_u32()?);
+        }
+        Ok(options)
+    }
 }
@@ -1,7 +1,10 @@
-use super::DeserializationError;
+use super::{DeserializationError, ProvingOptions, ProvingOptionsError};
 use vm_core::{
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
-    utils::collections::Vec,
+    utils::{
+        collections::Vec, string::ToString, ByteReader, ByteWriter, Serializable, SliceReader,
+    },
+    ProgramInfo, StackInputs, StackOutputs,
 };
 use winter_air::proof::StarkProof;
 
@@ -81,6 +84,161 @@ impl ExecutionProof {
     }
 }
 
+// PROOF BUNDLE
+// ================================================================================================
+
+/// A self-contained proof of correct execution of a Miden VM program.
+///
+/// Unlike [ExecutionProof], a bundle contains everything needed to verify the proof: the
+/// [ProgramInfo] of the executed program, the stack inputs and outputs, and the [ProvingOptions]
+/// with which the proof was generated. Bundles are serialized with a leading version byte so that
+/// the format can evolve without breaking existing readers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofBundle {
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    options: ProvingOptions,
+    proof: ExecutionProof,
+}
+
+impl ProofBundle {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Version of the serialization format of proof bundles.
+    pub const VERSION: u8 = 1;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [ProofBundle] from the specified public inputs, proving options and proof.
+    ///
+    /// # Errors
+    /// Returns an error if the hash function or the STARK protocol parameters specified by
+    /// `options` are different from the ones used to generate `proof`.
+    pub fn new(
+        program_info: ProgramInfo,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+        options: ProvingOptions,
+        proof: ExecutionProof,
+    ) -> Result<Self, ProvingOptionsError> {
+        if !is_consistent(&options, &proof) {
+            return Err(ProvingOptionsError::InconsistentWithProof);
+        }
+
+        Ok(Self {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            options,
+            proof,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns information about the program whose execution is proven by this bundle.
+    ///
+    /// Note: a bundle proves the execution of whichever program it specifies; it is up to the
+    /// caller to check that the program hash is the expected one.
+    pub const fn program_info(&self) -> &ProgramInfo {
+        &self.program_info
+    }
+
+    /// Returns the stack inputs against which the program was executed.
+    pub const fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the stack outputs produced by the program.
+    pub const fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Returns the options with which the proof was generated.
+    pub const fn options(&self) -> &ProvingOptions {
+        &self.options
+    }
+
+    /// Returns the proof of the program's execution.
+    pub const fn proof(&self) -> &ExecutionProof {
+        &self.proof
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this bundle into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![Self::VERSION];
+        self.program_info.write_into(&mut bytes);
+        self.stack_inputs.write_into(&mut bytes);
+        self.stack_outputs.write_into(&mut bytes);
+        self.options.write_into(&mut bytes);
+
+        let proof_bytes = self.proof.to_bytes();
+        bytes.write_u32(proof_bytes.len() as u32);
+        bytes.write_bytes(&proof_bytes);
+        bytes
+    }
+
+    /// Reads the source bytes, parsing a new proof bundle instance.
+    ///
+    /// # Errors
+    /// Returns an error if the bundle was serialized with an unsupported version of the format,
+    /// if any of its components cannot be parsed, or if the proving options are inconsistent with
+    /// the proof.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(source);
+        let version = source.read_u8()?;
+        if version != Self::VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "proof bundle version {version} is not supported; expected {}",
+                Self::VERSION
+            )));
+        }
+
+        let program_info = source.read()?;
+        let stack_inputs = source.read()?;
+        let stack_outputs = source.read()?;
+        let options = source.read()?;
+
+        let proof_len = source.read_u32()? as usize;
+        let proof = ExecutionProof::from_bytes(&source.read_vec(proof_len)?)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Self::new(program_info, stack_inputs, stack_outputs, options, proof)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+
+    // DESTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns components of this bundle.
+    pub fn into_parts(
+        self,
+    ) -> (ProgramInfo, StackInputs, StackOutputs, ProvingOptions, ExecutionProof) {
+        (
+            self.program_info,
+            self.stack_inputs,
+            self.stack_outputs,
+            self.options,
+            self.proof,
+        )
+    }
+}
+
+/// Returns true if the hash function and the STARK protocol parameters specified by `options` are
+/// the ones used to generate `proof`.
+fn is_consistent(options: &ProvingOptions, proof: &ExecutionProof) -> bool {
+    options.hash_fn == proof.hash_fn && &options.proof_options == proof.proof.options()
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
@@ -111,6 +269,14 @@ impl HashFunction {
             HashFunction::Rpo256 => Rpo256::COLLISION_RESISTANCE,
         }
     }
+
+    /// Returns the size (in bytes) of a digest produced by this hash function.
+    pub const fn digest_size(&self) -> usize {
+        match self {
+            HashFunction::Blake3_192 => 24,
+            HashFunction::Blake3_256 | HashFunction::Rpo256 => 32,
+        }
+    }
 }
 
 impl TryFrom<u8> for HashFunction {
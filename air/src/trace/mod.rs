@@ -1,5 +1,5 @@
 use core::ops::Range;
-use vm_core::utils::range;
+use vm_core::utils::{collections::Vec, range, string::String};
 
 pub mod chiplets;
 pub mod decoder;
@@ -95,3 +95,64 @@ pub const AUX_TRACE_WIDTH: usize = CHIPLETS_AUX_TRACE_RANGE.end;
 /// Number of random elements available to the prover after the commitment to the main trace
 /// segment.
 pub const AUX_TRACE_RAND_ELEMENTS: usize = 16;
+
+// COLUMN NAMES
+// ================================================================================================
+
+/// Returns the names of the main trace columns, in the order in which the columns appear in the
+/// main trace.
+///
+/// Columns of the decoder and stack traces which have a dedicated meaning are named after it;
+/// chiplet columns are shared among all chiplets and thus are named by their position only.
+pub fn main_trace_column_names() -> Vec<String> {
+    let mut names = Vec::with_capacity(TRACE_WIDTH);
+
+    // system columns
+    names.extend(["clk", "fmp", "ctx", "in_syscall"].map(String::from));
+    names.extend(FN_HASH_RANGE.map(|i| format!("fn_hash[{}]", i - FN_HASH_OFFSET)));
+
+    // decoder columns
+    names.push(String::from("addr"));
+    names.extend((0..decoder::NUM_OP_BITS).map(|i| format!("op_bits[{i}]")));
+    names.extend((0..decoder::NUM_HASHER_COLUMNS).map(|i| format!("hasher_state[{i}]")));
+    names.extend(["in_span", "group_count", "op_idx"].map(String::from));
+    names.extend((0..decoder::NUM_OP_BATCH_FLAGS).map(|i| format!("op_batch_flags[{i}]")));
+    names.extend((0..decoder::NUM_OP_BITS_EXTRA_COLS).map(|i| format!("op_bits_extra[{i}]")));
+
+    // stack columns
+    names.extend((0..stack::STACK_TOP_SIZE).map(|i| format!("s{i}")));
+    names.extend(["b0", "b1", "h0"].map(String::from));
+
+    // range checker columns
+    names.extend(["range_m", "range_v"].map(String::from));
+
+    // chiplets columns
+    names.extend((0..CHIPLETS_WIDTH).map(|i| format!("chiplets[{i}]")));
+
+    debug_assert_eq!(names.len(), TRACE_WIDTH);
+    names
+}
+
+/// Returns the names of the auxiliary trace columns, in the order in which the columns appear in
+/// the auxiliary trace.
+pub fn aux_trace_column_names() -> Vec<String> {
+    let mut names = vec![String::new(); AUX_TRACE_WIDTH];
+
+    // multiset check columns are named after their component and numbered from 1; bus columns
+    // are named after their component only
+    let multiset_columns = [
+        (DECODER_AUX_TRACE_RANGE, "decoder_p"),
+        (STACK_AUX_TRACE_RANGE, "stack_p"),
+        (HASHER_AUX_TRACE_RANGE, "hasher_p"),
+    ];
+    for (columns, prefix) in multiset_columns {
+        for (i, col_idx) in columns.enumerate() {
+            names[col_idx] = format!("{prefix}{}", i + 1);
+        }
+    }
+    names[RANGE_CHECK_AUX_TRACE_OFFSET] = String::from("range_b");
+    names[CHIPLETS_AUX_TRACE_OFFSET] = String::from("chiplets_b");
+
+    debug_assert!(names.iter().all(|name| !name.is_empty()));
+    names
+}
@@ -1,6 +1,6 @@
 use super::FieldElement;
 use core::ops::Range;
-use vm_core::{utils::collections::Vec, utils::range as create_range};
+use vm_core::utils::{collections::Vec, range as create_range, string::String};
 
 // BASIC CONSTRAINT OPERATORS
 // ================================================================================================
@@ -25,6 +25,14 @@ pub fn is_zero<E: FieldElement>(v: E) -> E {
     v
 }
 
+// CONSTRAINT NAMES
+// ================================================================================================
+
+/// Returns names for the specified number of constraints in the form `prefix[i]`.
+pub fn constraint_names(prefix: &str, count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("{prefix}[{i}]")).collect()
+}
+
 // TRAIT TO SIMPLIFY CONSTRAINT AGGREGATION
 // ================================================================================================
 
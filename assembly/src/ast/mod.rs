@@ -67,6 +67,9 @@ const MAX_INVOKED_IMPORTED_PROCS: usize = u16::MAX as usize;
 /// Maximum stack index at which a full word can start.
 const MAX_STACK_WORD_OFFSET: u8 = 12;
 
+/// Maximum number of 32-bit limbs in each operand of the `adv.push_udiv` instruction.
+const MAX_DIV_LIMBS: u8 = 16;
+
 // TYPE ALIASES
 // ================================================================================================
 type LocalProcMap = BTreeMap<ProcedureName, (u16, ProcedureAst)>;
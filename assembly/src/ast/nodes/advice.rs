@@ -1,7 +1,7 @@
 use super::{
     super::{
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, ToString,
-        MAX_STACK_WORD_OFFSET,
+        MAX_DIV_LIMBS, MAX_STACK_WORD_OFFSET,
     },
     serde::signatures,
 };
@@ -19,10 +19,14 @@ use vm_core::{AdviceInjector, Felt, SignatureKind, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64div,
+    PushU256div,
+    PushUdiv { a_limbs: u8, b_limbs: u8 },
     PushExt2intt,
     PushSmtGet,
     PushSmtSet,
     PushSmtPeek,
+    PushLowerBound,
+    PushLowerBoundImm { stride: u8 },
     PushMapVal,
     PushMapValImm { offset: u8 },
     PushMapValN,
@@ -40,10 +44,19 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64div => Self::DivU64,
+            PushU256div => Self::DivU256,
+            PushUdiv { a_limbs, b_limbs } => Self::DivUN {
+                dividend_limbs: (*a_limbs) as usize,
+                divisor_limbs: (*b_limbs) as usize,
+            },
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
             PushSmtPeek => Self::SmtPeek,
+            PushLowerBound => Self::MemLowerBound { stride: 1 },
+            PushLowerBoundImm { stride } => Self::MemLowerBound {
+                stride: (*stride) as usize,
+            },
             PushMapVal => Self::MapValueToStack {
                 include_len: false,
                 key_offset: 0,
@@ -77,10 +90,14 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => write!(f, "push_u64div"),
+            PushU256div => write!(f, "push_u256div"),
+            PushUdiv { a_limbs, b_limbs } => write!(f, "push_udiv.{a_limbs}.{b_limbs}"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
             PushSmtPeek => write!(f, "push_smtpeek"),
+            PushLowerBound => write!(f, "push_lowerbound"),
+            PushLowerBoundImm { stride } => write!(f, "push_lowerbound.{stride}"),
             PushMapVal => write!(f, "push_mapval"),
             PushMapValImm { offset } => write!(f, "push_mapval.{offset}"),
             PushMapValN => write!(f, "push_mapvaln"),
@@ -113,16 +130,31 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_U256DIV: u8 = 15;
+const PUSH_UDIV: u8 = 16;
+const PUSH_LOWERBOUND: u8 = 17;
+const PUSH_LOWERBOUND_IMM: u8 = 18;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => target.write_u8(PUSH_U64DIV),
+            PushU256div => target.write_u8(PUSH_U256DIV),
+            PushUdiv { a_limbs, b_limbs } => {
+                target.write_u8(PUSH_UDIV);
+                target.write_u8(*a_limbs);
+                target.write_u8(*b_limbs);
+            }
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
             PushSmtPeek => target.write_u8(PUSH_SMTPEEK),
+            PushLowerBound => target.write_u8(PUSH_LOWERBOUND),
+            PushLowerBoundImm { stride } => {
+                target.write_u8(PUSH_LOWERBOUND_IMM);
+                target.write_u8(*stride);
+            }
             PushMapVal => target.write_u8(PUSH_MAPVAL),
             PushMapValImm { offset } => {
                 target.write_u8(PUSH_MAPVAL_IMM);
@@ -153,10 +185,31 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64div),
+            PUSH_U256DIV => Ok(AdviceInjectorNode::PushU256div),
+            PUSH_UDIV => {
+                let a_limbs = source.read_u8()?;
+                let b_limbs = source.read_u8()?;
+                if !(1..=MAX_DIV_LIMBS).contains(&a_limbs)
+                    || !(1..=MAX_DIV_LIMBS).contains(&b_limbs)
+                {
+                    return Err(DeserializationError::InvalidValue(
+                        "invalid number of limbs".to_string(),
+                    ));
+                }
+                Ok(AdviceInjectorNode::PushUdiv { a_limbs, b_limbs })
+            }
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
             PUSH_SMTPEEK => Ok(AdviceInjectorNode::PushSmtPeek),
+            PUSH_LOWERBOUND => Ok(AdviceInjectorNode::PushLowerBound),
+            PUSH_LOWERBOUND_IMM => {
+                let stride = source.read_u8()?;
+                if stride == 0 {
+                    return Err(DeserializationError::InvalidValue("invalid stride".to_string()));
+                }
+                Ok(AdviceInjectorNode::PushLowerBoundImm { stride })
+            }
             PUSH_MAPVAL => Ok(AdviceInjectorNode::PushMapVal),
             PUSH_MAPVAL_IMM => {
                 let offset = source.read_u8()?;
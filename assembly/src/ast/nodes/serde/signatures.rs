@@ -2,6 +2,7 @@ use super::{ByteReader, ByteWriter, DeserializationError, ToString};
 use vm_core::SignatureKind;
 
 const RPOFALCON512: u8 = 0;
+const SCHNORRECGFP5: u8 = 1;
 
 /// Writes the provided [SignatureKind] into the provided target.
 pub fn write_options_into<W: ByteWriter>(target: &mut W, options: &SignatureKind) {
@@ -9,6 +10,9 @@ pub fn write_options_into<W: ByteWriter>(target: &mut W, options: &SignatureKind
         SignatureKind::RpoFalcon512 => {
             target.write_u8(RPOFALCON512);
         }
+        SignatureKind::SchnorrEcgfp5 => {
+            target.write_u8(SCHNORRECGFP5);
+        }
     }
 }
 
@@ -18,6 +22,7 @@ pub fn read_options_from<R: ByteReader>(
 ) -> Result<SignatureKind, DeserializationError> {
     match source.read_u8()? {
         RPOFALCON512 => Ok(SignatureKind::RpoFalcon512),
+        SCHNORRECGFP5 => Ok(SignatureKind::SchnorrEcgfp5),
         val => Err(DeserializationError::InvalidValue(val.to_string())),
     }
 }
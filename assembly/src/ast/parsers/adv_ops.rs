@@ -3,7 +3,7 @@ use super::{
     AdviceInjectorNode::*,
     Instruction::AdvInject,
     Node::{self, Instruction},
-    ParsingError, Token, MAX_STACK_WORD_OFFSET,
+    ParsingError, Token, MAX_DIV_LIMBS, MAX_STACK_WORD_OFFSET,
 };
 use vm_core::SignatureKind;
 
@@ -26,6 +26,21 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushU64div),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_u256div" => match op.num_parts() {
+            2 => AdvInject(PushU256div),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_udiv" => match op.num_parts() {
+            0..=3 => {
+                return Err(ParsingError::missing_param(op, "adv.push_udiv.<a_limbs>.<b_limbs>"))
+            }
+            4 => {
+                let a_limbs = parse_checked_param::<u8, _>(op, 2, 1..=MAX_DIV_LIMBS)?;
+                let b_limbs = parse_checked_param::<u8, _>(op, 3, 1..=MAX_DIV_LIMBS)?;
+                AdvInject(PushUdiv { a_limbs, b_limbs })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_ext2intt" => match op.num_parts() {
             2 => AdvInject(PushExt2intt),
             _ => return Err(ParsingError::extra_param(op)),
@@ -42,6 +57,18 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushSmtPeek),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_lowerbound" => match op.num_parts() {
+            2 => AdvInject(PushLowerBound),
+            3 => {
+                let stride = parse_checked_param::<u8, _>(op, 2, 1..=u8::MAX)?;
+                if stride == 1 {
+                    AdvInject(PushLowerBound)
+                } else {
+                    AdvInject(PushLowerBoundImm { stride })
+                }
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_mapval" => match op.num_parts() {
             2 => AdvInject(PushMapVal),
             3 => {
@@ -95,6 +122,9 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
                 "rpo_falcon512" => AdvInject(PushSignature {
                     kind: SignatureKind::RpoFalcon512,
                 }),
+                "schnorr_ecgfp5" => AdvInject(PushSignature {
+                    kind: SignatureKind::SchnorrEcgfp5,
+                }),
                 _ => return Err(ParsingError::invalid_param(op, 1)),
             },
             _ => return Err(ParsingError::extra_param(op)),
@@ -230,11 +230,19 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source = "begin adv.push_u64div adv.push_u256div adv.push_udiv.16.8 adv.push_mapval \
+        adv.push_smtget adv.push_lowerbound adv.push_lowerbound.2 adv.insert_mem end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64div)),
+        Node::Instruction(AdvInject(PushU256div)),
+        Node::Instruction(AdvInject(PushUdiv {
+            a_limbs: 16,
+            b_limbs: 8,
+        })),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
+        Node::Instruction(AdvInject(PushLowerBound)),
+        Node::Instruction(AdvInject(PushLowerBoundImm { stride: 2 })),
         Node::Instruction(AdvInject(InsertMem)),
     ];
 
@@ -105,6 +105,42 @@ pub enum AdviceInjector {
     /// the remainder respectively.
     DivU64,
 
+    /// Pushes the result of [u256] division (both the quotient and the remainder) onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, ..., b0, a7, a6, ..., a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, ..., b0, a7, a6, ..., a0, ...]
+    ///   Advice stack: [q0, q1, ..., q7, r0, r1, ..., r7, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+    /// respectively (with a0 representing the 32 least significant bits). Similarly, (q0, ..., q7)
+    /// and (r0, ..., r7) represent the quotient and the remainder respectively.
+    DivU256,
+
+    /// Pushes the result of division of two unsigned integers of arbitrary size (both the
+    /// quotient and the remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+    ///   Advice stack: [q0, ..., q_{m-1}, r0, ..., r_{n-1}, ...]
+    ///
+    /// Where m = `dividend_limbs` and n = `divisor_limbs`, (a0, ..., a_{m-1}) and
+    /// (b0, ..., b_{n-1}) are the 32-bit limbs of the dividend and the divisor respectively (with
+    /// a0 and b0 representing the 32 least significant bits). Similarly, the quotient is returned
+    /// using m limbs and the remainder using n limbs.
+    DivUN {
+        dividend_limbs: usize,
+        divisor_limbs: usize,
+    },
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -218,6 +254,24 @@ pub enum AdviceInjector {
     ///   Advice stack: [VALUE, ...]
     SmtPeek,
 
+    /// Pushes onto the advice stack the address of the first entry in a sorted array of words
+    /// which is not less than the specified key.
+    ///
+    /// Inputs:
+    ///   Operand stack: [KEY, start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [KEY, start_addr, end_addr, ...]
+    ///   Advice stack: [addr, ...]
+    ///
+    /// Where the array consists of entries of `stride` words located in
+    /// memory[start_addr..end_addr], and the first word of each entry is used as the sort key.
+    /// Words are compared lexicographically, with the element which is on top of the stack when
+    /// the word is loaded being the most significant one. If all entries are less than `KEY`,
+    /// `end_addr` is pushed onto the advice stack.
+    MemLowerBound { stride: usize },
+
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -299,11 +353,17 @@ impl fmt::Display for AdviceInjector {
                 }
             }
             Self::DivU64 => write!(f, "div_u64"),
+            Self::DivU256 => write!(f, "div_u256"),
+            Self::DivUN {
+                dividend_limbs,
+                divisor_limbs,
+            } => write!(f, "div_un.{dividend_limbs}.{divisor_limbs}"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
             Self::SmtSet => write!(f, "smt_set"),
             Self::SmtPeek => write!(f, "smt_peek"),
+            Self::MemLowerBound { stride } => write!(f, "mem_lower_bound.{stride}"),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
@@ -93,12 +93,14 @@ impl<'a> Iterator for DecoratorIterator<'a> {
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SignatureKind {
     RpoFalcon512,
+    SchnorrEcgfp5,
 }
 
 impl fmt::Display for SignatureKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::RpoFalcon512 => write!(f, "rpo_falcon512"),
+            Self::SchnorrEcgfp5 => write!(f, "schnorr_ecgfp5"),
         }
     }
 }
@@ -1,4 +1,7 @@
-use super::{vec, ByteWriter, Felt, InputError, Serializable, ToElements, Vec};
+use super::{
+    vec, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, InputError,
+    Serializable, ToElements, Vec,
+};
 use core::slice;
 
 // STACK INPUTS
@@ -8,7 +11,7 @@ use core::slice;
 ///
 /// The program execution expects the inputs to be a stack on the VM, and it will be stored in
 /// reversed order on this struct.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct StackInputs {
     values: Vec<Felt>,
 }
@@ -78,6 +81,14 @@ impl Serializable for StackInputs {
     }
 }
 
+impl Deserializable for StackInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_values = source.read_u32()?;
+        let values = (0..num_values).map(|_| Felt::read_from(source)).collect::<Result<_, _>>()?;
+        Ok(Self { values })
+    }
+}
+
 impl ToElements<Felt> for StackInputs {
     fn to_elements(&self) -> Vec<Felt> {
         self.values.to_vec()
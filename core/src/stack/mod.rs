@@ -1,10 +1,11 @@
 use super::{
     errors::{InputError, OutputError},
-    Felt, StackTopState, StarkField, ToElements,
+    Felt, StackTopState, StarkField, ToElements, Word, WORD_SIZE,
 };
 use winter_utils::{
     collections::{vec, Vec},
-    ByteWriter, Serializable,
+    string::ToString,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
 };
 
 mod inputs;
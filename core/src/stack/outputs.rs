@@ -1,6 +1,6 @@
 use super::{
-    ByteWriter, Felt, OutputError, Serializable, StackTopState, StarkField, ToElements, Vec,
-    STACK_TOP_SIZE,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, OutputError, Serializable,
+    StackTopState, StarkField, ToElements, ToString, Vec, Word, STACK_TOP_SIZE, WORD_SIZE,
 };
 
 // STACK OUTPUTS
@@ -108,6 +108,23 @@ impl StackOutputs {
             .expect("failed to convert vector to array")
     }
 
+    /// Returns the word located at the specified word index of the output stack, or None if the
+    /// output stack does not contain this word.
+    ///
+    /// Specifically, word 0 is defined by the first 4 elements of the stack, word 1 is defined by
+    /// the next 4 elements etc. The words are created in reverse order; for example, for word 0 the
+    /// top element of the stack will be at the last position in the word.
+    pub fn get_stack_word(&self, word_idx: usize) -> Option<Word> {
+        let word_start = word_idx * WORD_SIZE;
+        let elements = self.stack.get(word_start..word_start + WORD_SIZE)?;
+        Some([
+            Felt::new(elements[3]),
+            Felt::new(elements[2]),
+            Felt::new(elements[1]),
+            Felt::new(elements[0]),
+        ])
+    }
+
     /// Returns the overflow address outputs, which are the addresses required to reconstruct the
     /// overflow table (when combined with the stack overflow values) converted to integers.
     pub fn overflow_addrs(&self) -> &[u64] {
@@ -182,6 +199,20 @@ impl Serializable for StackOutputs {
     }
 }
 
+impl Deserializable for StackOutputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack_len = source.read_u32()?;
+        let stack = (0..stack_len).map(|_| source.read_u64()).collect::<Result<_, _>>()?;
+
+        let num_overflow_addrs = source.read_u32()?;
+        let overflow_addrs =
+            (0..num_overflow_addrs).map(|_| source.read_u64()).collect::<Result<_, _>>()?;
+
+        Self::new(stack, overflow_addrs)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 impl ToElements<Felt> for StackOutputs {
     fn to_elements(&self) -> Vec<Felt> {
         // infallible conversion from u64 to Felt is OK here because we check validity of u64
@@ -1,10 +1,11 @@
 use assembly::{Library, MaslLibrary};
 use miden::{
     crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
-    math::Felt,
-    utils::{Deserializable, SliceReader},
+    math::{Felt, StarkField},
+    recursion::RecursiveVerifierInputs,
+    utils::{Deserializable, Serializable, SliceReader},
     AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, Program, ProgramAst,
-    StackInputs, StackOutputs, Word,
+    ProofBundle, StackInputs, StackOutputs, TraceTable, Word,
 };
 use serde_derive::{Deserialize, Serialize};
 use std::{
@@ -37,7 +38,7 @@ impl Debug {
 
 /// Struct used to deserialize merkle data from input file. Merkle data can be represented as a
 /// merkle tree or a Sparse Merkle Tree.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub enum MerkleData {
     /// String representation of a merkle tree. The merkle tree is represented as a vector of
     /// 32 byte hex strings where each string represents a leaf in the tree.
@@ -65,7 +66,7 @@ pub enum MerkleData {
 /// - advice_stack
 /// - advice_map
 /// - merkle_store
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct InputFile {
     /// String representation of the initial operand stack, composed of chained field elements.
     pub operand_stack: Vec<String>,
@@ -113,6 +114,54 @@ impl InputFile {
         Ok(inputs)
     }
 
+    /// Returns a new [InputFile] with the inputs required to verify a proof inside Miden VM using
+    /// the STARK verifier from the standard library.
+    pub fn from_recursive_verifier_inputs(inputs: &RecursiveVerifierInputs) -> Self {
+        let operand_stack = inputs.stack_values().iter().map(|v| v.to_string()).collect();
+        let advice_stack = inputs.advice_stack().iter().map(|v| v.to_string()).collect();
+        let advice_map = inputs
+            .advice_map()
+            .iter()
+            .map(|(key, values)| (hex::encode(key), values.iter().map(|v| v.as_int()).collect()))
+            .collect();
+        let merkle_store = inputs
+            .partial_trees()
+            .iter()
+            .map(|tree| {
+                let leaves = tree
+                    .leaves()
+                    .map(|(index, leaf)| {
+                        (
+                            (index.depth(), index.value()),
+                            format!("0x{}", hex::encode(leaf.as_bytes())),
+                        )
+                    })
+                    .collect();
+                MerkleData::PartialMerkleTree(leaves)
+            })
+            .collect();
+
+        Self {
+            operand_stack,
+            advice_stack: Some(advice_stack),
+            advice_map: Some(advice_map),
+            merkle_store: Some(merkle_store),
+        }
+    }
+
+    /// Writes this input file to the specified path.
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        println!("Creating input file `{}`", path.display());
+
+        let file = fs::File::create(path)
+            .map_err(|err| format!("Failed to create input file `{}` - {}", path.display(), err))?;
+
+        println!("Writing data to input file");
+
+        serde_json::to_writer_pretty(file, self)
+            .map_err(|err| format!("Failed to write input data - {}", err))
+    }
+
     /// Parse advice provider data from the input file.
     pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
         let mut advice_inputs = AdviceInputs::default();
@@ -502,6 +551,71 @@ impl ProofFile {
     }
 }
 
+// PROOF BUNDLE FILE
+// ================================================================================================
+
+pub struct ProofBundleFile;
+
+/// Helper methods to interact with proof bundle files
+impl ProofBundleFile {
+    /// Read proof bundle from file
+    pub fn read(bundle_path: &Path) -> Result<ProofBundle, String> {
+        println!("Reading proof bundle file `{}`", bundle_path.display());
+
+        // read the file to bytes
+        let file = fs::read(bundle_path).map_err(|err| {
+            format!("Failed to open proof bundle file `{}` - {}", bundle_path.display(), err)
+        })?;
+
+        // deserialize bytes into a proof bundle
+        ProofBundle::from_bytes(&file)
+            .map_err(|err| format!("Failed to decode proof bundle data - {}", err))
+    }
+
+    /// Write proof bundle to file
+    pub fn write(bundle: &ProofBundle, bundle_path: &Path) -> Result<(), String> {
+        println!("Creating proof bundle file `{}`", bundle_path.display());
+
+        let mut file = fs::File::create(bundle_path).map_err(|err| {
+            format!("Failed to create proof bundle file `{}` - {}", bundle_path.display(), err)
+        })?;
+
+        let bundle_bytes = bundle.to_bytes();
+
+        println!("Writing data to proof bundle file - size {} KB", bundle_bytes.len() / 1024);
+
+        file.write_all(&bundle_bytes)
+            .map_err(|err| format!("Failed to write proof bundle file - {}", err))
+    }
+}
+
+// TRACE FILE
+// ================================================================================================
+
+pub struct TraceFile;
+
+/// Helper methods to interact with execution trace files
+impl TraceFile {
+    /// Write the execution trace table to file; the table is written in CSV format if the file
+    /// has a `.csv` extension, and in binary format otherwise.
+    pub fn write(table: &TraceTable, trace_path: &Path) -> Result<(), String> {
+        println!("Creating trace file `{}`", trace_path.display());
+
+        let mut file = fs::File::create(trace_path).map_err(|err| {
+            format!("Failed to create trace file `{}` - {}", trace_path.display(), err)
+        })?;
+
+        if trace_path.extension().is_some_and(|ext| ext == "csv") {
+            table
+                .write_csv(std::io::BufWriter::new(file))
+                .map_err(|err| format!("Failed to write trace file - {}", err))
+        } else {
+            file.write_all(&table.to_bytes())
+                .map_err(|err| format!("Failed to write trace file - {}", err))
+        }
+    }
+}
+
 // PROGRAM HASH
 // ================================================================================================
 
@@ -2,6 +2,8 @@ mod bundle;
 mod compile;
 mod data;
 mod debug;
+mod param_search;
+mod proof_info;
 mod prove;
 mod repl;
 mod run;
@@ -11,6 +13,8 @@ pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use data::InputFile;
 pub use debug::DebugCmd;
+pub use param_search::ParamSearchCmd;
+pub use proof_info::ProofInfoCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
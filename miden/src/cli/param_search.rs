@@ -0,0 +1,313 @@
+use super::prove::parse_hash_fn;
+use air::{
+    trace::{AUX_TRACE_WIDTH, TRACE_WIDTH as MAIN_TRACE_WIDTH},
+    ProcessorAir, MAX_BLOWUP_FACTOR, MAX_FRI_FOLDING_FACTOR, MAX_FRI_REMAINDER_DEGREE,
+    MAX_GRINDING_FACTOR, MAX_NUM_QUERIES, MIN_BLOWUP_FACTOR, MIN_FRI_FOLDING_FACTOR,
+};
+use clap::Parser;
+use miden::{FieldExtension, HashFunction, ProvingOptions};
+use std::{cmp::Ordering, iter};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Values of the STARK protocol parameters considered by the search, in addition to the ones
+/// derived from the bounds accepted by the prover.
+const FIELD_EXTENSIONS: [FieldExtension; 2] = [FieldExtension::Quadratic, FieldExtension::Cubic];
+const MIN_FRI_REMAINDER_MAX_DEGREE: usize = 7;
+const GRINDING_FACTOR_STEP: u32 = 4;
+
+/// Approximate number of base field multiplications the constraint evaluator performs per row of
+/// the constraint evaluation domain.
+const CONSTRAINT_EVALUATION_COST: f64 = 1000.0;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(
+    about = "Search for proving parameters which minimize proof size for a target security level"
+)]
+pub struct ParamSearchCmd {
+    /// Target security level (in bits)
+    #[clap(short = 's', long = "security", default_value = "96")]
+    security: u32,
+
+    /// Length of the execution trace (number of cycles); rounded up to the next power of two
+    #[clap(short = 't', long = "trace-length", default_value = "65536")]
+    trace_length: usize,
+
+    /// Hash function used to generate proofs: blake3-192, blake3-256 or rpo256; by default,
+    /// BLAKE3 with a digest large enough for the target security level is used
+    #[clap(long = "hash-fn")]
+    hash_fn: Option<String>,
+
+    /// Maximum number of parameter sets to print
+    #[clap(short = 'n', long = "num-results", default_value = "10")]
+    num_results: usize,
+}
+
+impl ParamSearchCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Search proving parameters");
+        println!("============================================================");
+
+        let hash_fn = match &self.hash_fn {
+            Some(hash_fn) => parse_hash_fn(hash_fn)?,
+            None if self.security <= 96 => HashFunction::Blake3_192,
+            None => HashFunction::Blake3_256,
+        };
+        if hash_fn.collision_resistance() < self.security {
+            return Err(format!(
+                "Hash function {hash_fn:?} provides at most {} bits of security",
+                hash_fn.collision_resistance()
+            ));
+        }
+        let trace_len = self.trace_length.next_power_of_two();
+
+        // the blowup factor needed by the constraints of the VM is also the number of columns of
+        // the constraint composition polynomial
+        let min_blowup_factor = ProcessorAir::min_blowup_factor();
+
+        let candidates = search(self.security, trace_len, hash_fn, min_blowup_factor);
+        if candidates.is_empty() {
+            return Err(format!(
+                "No proving parameters achieve {} bits of security for traces of {trace_len} rows",
+                self.security
+            ));
+        }
+
+        // prover time is reported relative to the fastest parameter set found
+        let min_cost = candidates.iter().map(|c| c.prover_cost).fold(f64::INFINITY, f64::min);
+
+        println!(
+            "Parameters achieving at least {} bits of security for traces of {trace_len} rows \
+            with {hash_fn:?}, ordered by predicted proof size:\n",
+            self.security
+        );
+        println!(
+            "{:>8} {:>10} {:>8} {:>10} {:>9} {:>8} {:>9} {:>12} {:>11}",
+            "blowup",
+            "extension",
+            "folding",
+            "remainder",
+            "grinding",
+            "queries",
+            "security",
+            "proof size",
+            "prover time"
+        );
+        for candidate in pareto_front(candidates).iter().take(self.num_results) {
+            candidate.print(min_cost);
+        }
+
+        // for comparison, print the presets for the same security level
+        let presets = if self.security <= 96 {
+            [
+                ("96-bit", ProvingOptions::with_96_bit_security(false)),
+                ("96-bit size-optimized", ProvingOptions::with_size_optimized_96_bit_security()),
+            ]
+        } else {
+            [
+                ("128-bit", ProvingOptions::with_128_bit_security(false)),
+                ("128-bit size-optimized", ProvingOptions::with_size_optimized_128_bit_security()),
+            ]
+        };
+        println!("\nPresets:");
+        for (name, options) in presets {
+            println!("{name}");
+            Candidate::new(options, trace_len, min_blowup_factor).print(min_cost);
+        }
+
+        println!("\nProver time is relative to the fastest parameter set achieving the target");
+        println!("security level; it is a rough estimate based on the number of field operations");
+        println!("and hash function invocations performed by the prover.");
+
+        Ok(())
+    }
+}
+
+// SEARCH
+// ================================================================================================
+
+/// A set of proving parameters together with its predicted costs.
+struct Candidate {
+    options: ProvingOptions,
+    security_level: u32,
+    proof_size: usize,
+    prover_cost: f64,
+}
+
+impl Candidate {
+    fn new(options: ProvingOptions, trace_len: usize, num_composition_columns: usize) -> Self {
+        Self {
+            security_level: options.security_level(trace_len),
+            proof_size: options.estimate_proof_size(trace_len),
+            prover_cost: estimate_prover_cost(&options, trace_len, num_composition_columns),
+            options,
+        }
+    }
+
+    fn print(&self, min_cost: f64) {
+        let options = &self.options.proof_options;
+        let fri_options = options.to_fri_options();
+        println!(
+            "{:>8} {:>10} {:>8} {:>10} {:>9} {:>8} {:>9} {:>9.1} KB {:>10.2}x",
+            options.blowup_factor(),
+            format!("{:?}", options.field_extension()),
+            fri_options.folding_factor(),
+            fri_options.remainder_max_degree(),
+            options.grinding_factor(),
+            options.num_queries(),
+            self.security_level,
+            self.proof_size as f64 / 1024.0,
+            self.prover_cost / min_cost
+        );
+    }
+}
+
+/// Returns all parameter sets which achieve the target security level for traces of the
+/// specified length; for each combination of parameters, the smallest sufficient number of
+/// queries is used.
+///
+/// All considered parameter values are within the bounds accepted by [ProvingOptions::try_new()]
+/// and the blowup factors are at least `min_blowup_factor`; thus, the options are built without
+/// validating them again.
+fn search(
+    security: u32,
+    trace_len: usize,
+    hash_fn: HashFunction,
+    min_blowup_factor: usize,
+) -> Vec<Candidate> {
+    let blowup_factors = powers_of_two(min_blowup_factor.max(MIN_BLOWUP_FACTOR), MAX_BLOWUP_FACTOR);
+    let fri_folding_factors = powers_of_two(MIN_FRI_FOLDING_FACTOR, MAX_FRI_FOLDING_FACTOR);
+    let fri_remainder_max_degrees =
+        powers_of_two(MIN_FRI_REMAINDER_MAX_DEGREE + 1, MAX_FRI_REMAINDER_DEGREE + 1)
+            .map(|size| size - 1);
+    let grinding_factors = (0..=MAX_GRINDING_FACTOR).step_by(GRINDING_FACTOR_STEP as usize);
+
+    let mut result = Vec::new();
+    for blowup_factor in blowup_factors {
+        for field_extension in FIELD_EXTENSIONS {
+            for fri_folding_factor in fri_folding_factors.clone() {
+                for fri_remainder_max_degree in fri_remainder_max_degrees.clone() {
+                    for grinding_factor in grinding_factors.clone() {
+                        let options = |num_queries| {
+                            ProvingOptions::new(
+                                num_queries,
+                                blowup_factor,
+                                grinding_factor,
+                                field_extension,
+                                fri_folding_factor,
+                                fri_remainder_max_degree,
+                                hash_fn,
+                            )
+                        };
+
+                        // the security level grows with the number of queries
+                        let options = (1..=MAX_NUM_QUERIES)
+                            .map(options)
+                            .find(|options| options.security_level(trace_len) >= security);
+                        if let Some(options) = options {
+                            result.push(Candidate::new(options, trace_len, min_blowup_factor));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Returns the powers of two between `min` and `max` (inclusive); `min` must be a power of two.
+fn powers_of_two(min: usize, max: usize) -> impl Iterator<Item = usize> + Clone {
+    iter::successors(Some(min), |value| value.checked_mul(2)).take_while(move |value| *value <= max)
+}
+
+/// Returns the candidates for which no other candidate has both smaller proof size and smaller
+/// prover time, ordered by proof size.
+fn pareto_front(mut candidates: Vec<Candidate>) -> Vec<Candidate> {
+    candidates.sort_by(|a, b| {
+        a.proof_size
+            .cmp(&b.proof_size)
+            .then(a.prover_cost.partial_cmp(&b.prover_cost).unwrap_or(Ordering::Equal))
+    });
+
+    let mut result: Vec<Candidate> = Vec::new();
+    for candidate in candidates {
+        if result.last().map_or(true, |last| candidate.prover_cost < last.prover_cost) {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+/// Returns an estimate of the work performed by the prover to generate a proof for a trace of the
+/// specified length, in units of base field multiplications.
+///
+/// The estimate accounts for the low-degree extension of the trace and composition polynomials,
+/// constraint evaluation, hashing of the committed rows and of the Merkle trees, FRI layer
+/// construction, and query seed grinding. `num_composition_columns` is the number of columns of
+/// the constraint composition polynomial, which is equal to [ProcessorAir::min_blowup_factor()].
+fn estimate_prover_cost(
+    options: &ProvingOptions,
+    trace_len: usize,
+    num_composition_columns: usize,
+) -> f64 {
+    let proof_options = &options.proof_options;
+    let fri_options = proof_options.to_fri_options();
+    let ext_degree = proof_options.field_extension().degree() as usize;
+    let ext_mul_cost = match proof_options.field_extension() {
+        FieldExtension::None => 1.0,
+        FieldExtension::Quadratic => 3.0,
+        FieldExtension::Cubic => 6.0,
+    };
+    // cost of hashing a single field element, and of merging two digests
+    let (element_hash_cost, merge_cost) = match options.hash_fn() {
+        HashFunction::Blake3_192 | HashFunction::Blake3_256 => (4.0, 16.0),
+        HashFunction::Rpo256 => (160.0, 1300.0),
+    };
+
+    let lde_domain_size = (trace_len * proof_options.blowup_factor()) as f64;
+    let log_lde_domain_size = lde_domain_size.log2();
+    let merkle_tree_cost = |num_leaves: f64| num_leaves * merge_cost;
+
+    // interpolation and evaluation of the main and auxiliary trace columns
+    let trace_lde = lde_domain_size
+        * log_lde_domain_size
+        * (MAIN_TRACE_WIDTH as f64 + AUX_TRACE_WIDTH as f64 * ext_mul_cost);
+    let trace_commitment = lde_domain_size
+        * (MAIN_TRACE_WIDTH + AUX_TRACE_WIDTH * ext_degree) as f64
+        * element_hash_cost
+        + 2.0 * merkle_tree_cost(lde_domain_size);
+
+    // evaluation of the constraints over the constraint evaluation domain, and evaluation of the
+    // constraint composition polynomial over the LDE domain
+    let ce_domain_size = (trace_len * num_composition_columns) as f64;
+    let constraint_evaluation = ce_domain_size * CONSTRAINT_EVALUATION_COST * ext_mul_cost;
+    let constraint_commitment = num_composition_columns as f64
+        * lde_domain_size
+        * (log_lde_domain_size * ext_mul_cost + ext_degree as f64 * element_hash_cost)
+        + merkle_tree_cost(lde_domain_size);
+
+    // DEEP composition and FRI layers
+    let num_columns = MAIN_TRACE_WIDTH + AUX_TRACE_WIDTH + num_composition_columns;
+    let deep_composition = lde_domain_size * num_columns as f64 * ext_mul_cost;
+    let mut fri = 0.0;
+    let mut domain_size = lde_domain_size;
+    for _ in 0..fri_options.num_fri_layers(lde_domain_size as usize) {
+        let num_leaves = domain_size / fri_options.folding_factor() as f64;
+        fri += domain_size * (ext_degree as f64 * element_hash_cost + ext_mul_cost)
+            + merkle_tree_cost(num_leaves);
+        domain_size = num_leaves;
+    }
+
+    // on average, 2^grinding_factor nonces need to be tried
+    let grinding = 2f64.powi(proof_options.grinding_factor() as i32) * merge_cost;
+
+    trace_lde
+        + trace_commitment
+        + constraint_evaluation
+        + constraint_commitment
+        + deep_composition
+        + fri
+        + grinding
+}
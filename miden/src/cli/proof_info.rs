@@ -0,0 +1,160 @@
+use clap::Parser;
+use miden::{utils::Serializable, ExecutionProof};
+use serde_derive::Serialize;
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Print information about an execution proof")]
+pub struct ProofInfoCmd {
+    /// Path to proof file
+    #[clap(value_parser)]
+    proof_file: PathBuf,
+
+    /// Print the information in JSON format
+    #[clap(long = "json")]
+    json: bool,
+}
+
+impl ProofInfoCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        let bytes = fs::read(&self.proof_file).map_err(|err| {
+            format!("Failed to open proof file `{}` - {}", self.proof_file.display(), err)
+        })?;
+        let proof = ExecutionProof::from_bytes(&bytes)
+            .map_err(|err| format!("Failed to decode proof data - {}", err))?;
+
+        let info = ProofInfo::new(&proof, bytes.len());
+        if self.json {
+            let json = serde_json::to_string_pretty(&info)
+                .map_err(|err| format!("Failed to serialize proof info - {}", err))?;
+            println!("{json}");
+        } else {
+            println!("============================================================");
+            println!("Proof info");
+            println!("============================================================");
+            info.print();
+        }
+
+        Ok(())
+    }
+}
+
+// PROOF INFO
+// ================================================================================================
+
+/// Parameters, dimensions and size breakdown of an execution proof.
+#[derive(Debug, Serialize)]
+struct ProofInfo {
+    hash_fn: String,
+    trace_length: usize,
+    main_trace_width: usize,
+    aux_trace_width: usize,
+    blowup_factor: usize,
+    lde_domain_size: usize,
+    num_queries: usize,
+    grinding_factor: u32,
+    field_extension: String,
+    fri_folding_factor: usize,
+    fri_remainder_max_degree: usize,
+    num_fri_layers: usize,
+    security_level: u32,
+    size: ProofSize,
+}
+
+/// Sizes (in bytes) of the parts of a serialized execution proof.
+#[derive(Debug, Serialize)]
+struct ProofSize {
+    commitments: usize,
+    trace_queries: usize,
+    constraint_queries: usize,
+    ood_frame: usize,
+    fri_proof: usize,
+    other: usize,
+    total: usize,
+}
+
+impl ProofInfo {
+    /// Collects information about the specified proof; `total_size` is the size of the serialized
+    /// proof in bytes.
+    fn new(proof: &ExecutionProof, total_size: usize) -> Self {
+        let stark_proof = proof.stark_proof();
+        let trace_info = stark_proof.get_trace_info();
+        let options = stark_proof.options();
+        let fri_options = options.to_fri_options();
+
+        let commitments = stark_proof.commitments.to_bytes().len();
+        let trace_queries = stark_proof.trace_queries.iter().map(|q| q.to_bytes().len()).sum();
+        let constraint_queries = stark_proof.constraint_queries.to_bytes().len();
+        let ood_frame = stark_proof.ood_frame.to_bytes().len();
+        let fri_proof = stark_proof.fri_proof.to_bytes().len();
+
+        // the rest of the proof consists of the proof context, the proof-of-work nonce, and the
+        // hash function identifier
+        let other =
+            total_size - (commitments + trace_queries + constraint_queries + ood_frame + fri_proof);
+
+        Self {
+            hash_fn: format!("{:?}", proof.hash_fn()),
+            trace_length: trace_info.length(),
+            main_trace_width: trace_info.layout().main_trace_width(),
+            aux_trace_width: trace_info.layout().aux_trace_width(),
+            blowup_factor: options.blowup_factor(),
+            lde_domain_size: stark_proof.lde_domain_size(),
+            num_queries: options.num_queries(),
+            grinding_factor: options.grinding_factor(),
+            field_extension: format!("{:?}", options.field_extension()),
+            fri_folding_factor: fri_options.folding_factor(),
+            fri_remainder_max_degree: fri_options.remainder_max_degree(),
+            num_fri_layers: stark_proof.fri_proof.num_layers(),
+            security_level: proof.security_level(),
+            size: ProofSize {
+                commitments,
+                trace_queries,
+                constraint_queries,
+                ood_frame,
+                fri_proof,
+                other,
+                total: total_size,
+            },
+        }
+    }
+
+    /// Prints the information in a human-readable format.
+    fn print(&self) {
+        println!("Hash function:            {}", self.hash_fn);
+        println!("Trace length:             {}", self.trace_length);
+        println!(
+            "Trace width:              {} ({} main, {} auxiliary)",
+            self.main_trace_width + self.aux_trace_width,
+            self.main_trace_width,
+            self.aux_trace_width
+        );
+        println!("Blowup factor:            {}", self.blowup_factor);
+        println!("LDE domain size:          {}", self.lde_domain_size);
+        println!("Number of queries:        {}", self.num_queries);
+        println!("Grinding factor:          {}", self.grinding_factor);
+        println!("Field extension:          {}", self.field_extension);
+        println!("FRI folding factor:       {}", self.fri_folding_factor);
+        println!("FRI remainder max degree: {}", self.fri_remainder_max_degree);
+        println!("FRI layers:               {}", self.num_fri_layers);
+        println!("Security level:           {} bits (conjectured)", self.security_level);
+        println!("Proof size:               {}", format_size(self.size.total));
+        println!("  commitments:            {}", format_size(self.size.commitments));
+        println!("  trace queries:          {}", format_size(self.size.trace_queries));
+        println!("  constraint queries:     {}", format_size(self.size.constraint_queries));
+        println!("  OOD frame:              {}", format_size(self.size.ood_frame));
+        println!("  FRI proof:              {}", format_size(self.size.fri_proof));
+        println!("  other:                  {}", format_size(self.size.other));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn format_size(size: usize) -> String {
+    if size < 1024 {
+        format!("{size} bytes")
+    } else {
+        format!("{size} bytes ({:.1} KB)", size as f64 / 1024.0)
+    }
+}
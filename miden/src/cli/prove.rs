@@ -1,7 +1,12 @@
-use super::data::{Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
+use super::data::{
+    Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofBundleFile, ProofFile,
+};
 use clap::Parser;
-use miden::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError};
+use miden::{
+    recursion::generate_recursive_verifier_inputs, FieldExtension, HashFunction, ProgramInfo,
+    ProofBundle, ProvingOptions,
+};
+use processor::{DefaultHost, ExecutionOptions};
 use std::{io::Write, path::PathBuf, time::Instant};
 
 // TODO check if clap is supporting automatic generation of list values of hash function
@@ -12,6 +17,10 @@ pub struct ProveCmd {
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
+    /// Path to proof bundle file which packages the proof together with its public inputs
+    #[clap(short = 'b', long = "bundle", value_parser)]
+    bundle_file: Option<PathBuf>,
+
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
@@ -44,20 +53,92 @@ pub struct ProveCmd {
     #[clap(short = 'r', long = "recursive")]
     recursive: bool,
 
-    /// Security level for execution proofs generated by the VM
+    /// Path to a file into which inputs for verifying the proof inside Miden VM are written
+    #[clap(long = "recursive-inputs", value_parser, requires = "recursive")]
+    recursive_inputs_file: Option<PathBuf>,
+
+    /// Security level for execution proofs generated by the VM; the parameters of the preset can
+    /// be overridden individually using the options below
     #[clap(short = 's', long = "security", default_value = "96bits")]
     security: String,
+
+    /// Use the preset for the security level which minimizes proof size rather than prover time
+    #[clap(long = "size-optimized", conflicts_with = "recursive")]
+    size_optimized: bool,
+
+    /// Generate the proof using the low-memory prover, trading prover time for peak memory usage
+    #[clap(long = "low-memory")]
+    low_memory: bool,
+
+    /// Blowup factor of the low-degree extension domain
+    #[clap(long = "blowup-factor")]
+    blowup_factor: Option<usize>,
+
+    /// Field extension used for composition: none, quadratic or cubic
+    #[clap(long = "field-extension")]
+    field_extension: Option<String>,
+
+    /// Folding factor of the FRI protocol
+    #[clap(long = "folding-factor")]
+    fri_folding_factor: Option<usize>,
+
+    /// Number of proof-of-work bits required for query seed grinding
+    #[clap(long = "grinding-factor")]
+    grinding_factor: Option<u32>,
+
+    /// Hash function used to generate the proof: blake3-192, blake3-256 or rpo256
+    #[clap(long = "hash-fn")]
+    hash_fn: Option<String>,
+
+    /// Number of queries of the FRI protocol
+    #[clap(long = "num-queries")]
+    num_queries: Option<usize>,
+
+    /// Maximum degree of the remainder polynomial of the FRI protocol
+    #[clap(long = "remainder-degree")]
+    fri_remainder_max_degree: Option<usize>,
 }
 
 impl ProveCmd {
-    pub fn get_proof_options(&self) -> Result<ProvingOptions, ExecutionOptionsError> {
-        let exec_options = ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles)?;
-        Ok(match self.security.as_str() {
-            "96bits" => ProvingOptions::with_96_bit_security(self.recursive),
-            "128bits" => ProvingOptions::with_128_bit_security(self.recursive),
-            other => panic!("{} is not a valid security setting", other),
+    pub fn get_proof_options(&self) -> Result<ProvingOptions, String> {
+        let exec_options = ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles)
+            .map_err(|err| format!("{err}"))?;
+        let preset = match (self.security.as_str(), self.size_optimized) {
+            ("96bits", false) => ProvingOptions::with_96_bit_security(self.recursive),
+            ("128bits", false) => ProvingOptions::with_128_bit_security(self.recursive),
+            ("96bits", true) => ProvingOptions::with_size_optimized_96_bit_security(),
+            ("128bits", true) => ProvingOptions::with_size_optimized_128_bit_security(),
+            (other, _) => return Err(format!("{other} is not a valid security setting")),
+        };
+
+        // override the parameters of the preset with the ones specified explicitly
+        let proof_options = &preset.proof_options;
+        let fri_options = proof_options.to_fri_options();
+        let field_extension = match &self.field_extension {
+            Some(field_extension) => parse_field_extension(field_extension)?,
+            None => proof_options.field_extension(),
+        };
+        let hash_fn = match &self.hash_fn {
+            Some(hash_fn) => parse_hash_fn(hash_fn)?,
+            None => preset.hash_fn(),
+        };
+
+        let options = ProvingOptions::try_new(
+            self.num_queries.unwrap_or(proof_options.num_queries()),
+            self.blowup_factor.unwrap_or(proof_options.blowup_factor()),
+            self.grinding_factor.unwrap_or(proof_options.grinding_factor()),
+            field_extension,
+            self.fri_folding_factor.unwrap_or(fri_options.folding_factor()),
+            self.fri_remainder_max_degree.unwrap_or(fri_options.remainder_max_degree()),
+            hash_fn,
+        )
+        .map_err(|err| format!("Invalid proving options - {err}"))?;
+
+        let mut options = options.with_execution_options(exec_options);
+        if self.low_memory {
+            options = options.with_low_memory_proving();
         }
-        .with_execution_options(exec_options))
+        Ok(options)
     }
 
     pub fn execute(&self) -> Result<(), String> {
@@ -89,11 +170,12 @@ impl ProveCmd {
         let stack_inputs = input_data.parse_stack_inputs()?;
         let host = DefaultHost::new(input_data.parse_advice_provider()?);
 
-        let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
+        let proving_options = self.get_proof_options()?;
+        print_proving_options(&proving_options);
 
         // execute program and generate proof
         let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, host, proving_options)
+            prover::prove(&program, stack_inputs.clone(), host, proving_options.clone())
                 .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
         println!(
@@ -101,6 +183,34 @@ impl ProveCmd {
             hex::encode(program_hash),
             now.elapsed().as_millis()
         );
+        println!("Proof security level: {} bits", proof.security_level());
+
+        let program_info = ProgramInfo::from(program);
+
+        // write inputs for verifying the proof inside Miden VM to file
+        if let Some(recursive_inputs_path) = &self.recursive_inputs_file {
+            let inputs = generate_recursive_verifier_inputs(
+                proof.clone(),
+                program_info.clone(),
+                stack_inputs.clone(),
+                stack_outputs.clone(),
+            )
+            .map_err(|err| format!("Failed to generate recursive verifier inputs - {err}"))?;
+            InputFile::from_recursive_verifier_inputs(&inputs).write(recursive_inputs_path)?;
+        }
+
+        // write the proof together with its public inputs to file
+        if let Some(bundle_path) = &self.bundle_file {
+            let bundle = ProofBundle::new(
+                program_info,
+                stack_inputs,
+                stack_outputs.clone(),
+                proving_options,
+                proof.clone(),
+            )
+            .map_err(|err| format!("Failed to create proof bundle - {err}"))?;
+            ProofBundleFile::write(&bundle, bundle_path)?;
+        }
 
         // write proof to file
         ProofFile::write(proof, &self.proof_file, &self.assembly_file)?;
@@ -123,3 +233,51 @@ impl ProveCmd {
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prints the STARK protocol parameters of the specified options, together with the security level
+/// achievable with them.
+fn print_proving_options(options: &ProvingOptions) {
+    let proof_options = &options.proof_options;
+    let fri_options = proof_options.to_fri_options();
+    println!(
+        "Proving options: {} queries, blowup factor {}, grinding factor {}, {:?} field extension, \
+        FRI folding factor {}, FRI remainder max degree {}, {:?} hash function",
+        proof_options.num_queries(),
+        proof_options.blowup_factor(),
+        proof_options.grinding_factor(),
+        proof_options.field_extension(),
+        fri_options.folding_factor(),
+        fri_options.remainder_max_degree(),
+        options.hash_fn(),
+    );
+
+    // the security level decreases with the length of the trace; so, we report the security level
+    // for the longest trace which can be generated within the cycle limit
+    let max_trace_len = (options.execution_options().max_cycles() as usize).next_power_of_two();
+    println!(
+        "Security level: at least {} bits for traces of up to 2^{} rows",
+        options.security_level(max_trace_len),
+        max_trace_len.ilog2()
+    );
+}
+
+fn parse_field_extension(field_extension: &str) -> Result<FieldExtension, String> {
+    match field_extension {
+        "none" => Ok(FieldExtension::None),
+        "quadratic" => Ok(FieldExtension::Quadratic),
+        "cubic" => Ok(FieldExtension::Cubic),
+        other => Err(format!("{other} is not a valid field extension")),
+    }
+}
+
+pub(super) fn parse_hash_fn(hash_fn: &str) -> Result<HashFunction, String> {
+    match hash_fn {
+        "blake3-192" => Ok(HashFunction::Blake3_192),
+        "blake3-256" => Ok(HashFunction::Blake3_256),
+        "rpo256" => Ok(HashFunction::Rpo256),
+        other => Err(format!("{other} is not a valid hash function")),
+    }
+}
@@ -1,4 +1,4 @@
-use super::data::{Debug, InputFile, Libraries, OutputFile, ProgramFile};
+use super::data::{Debug, InputFile, Libraries, OutputFile, ProgramFile, TraceFile};
 use clap::Parser;
 use processor::{DefaultHost, ExecutionOptions};
 use std::{path::PathBuf, time::Instant};
@@ -33,6 +33,11 @@ pub struct RunCmd {
     /// Path to output file
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+
+    /// Path to a file into which the main execution trace is exported (CSV if the file has a
+    /// .csv extension, binary otherwise)
+    #[clap(short = 't', long = "trace", value_parser)]
+    trace_file: Option<PathBuf>,
 }
 
 impl RunCmd {
@@ -77,6 +82,11 @@ impl RunCmd {
             println!("Output: {:?}", trace.stack_outputs().stack_truncated(self.num_outputs));
         }
 
+        // export the execution trace if a trace file was specified
+        if let Some(trace_path) = &self.trace_file {
+            TraceFile::write(&trace.main_trace_table(), trace_path)?;
+        }
+
         // calculate the percentage of padded rows
         let padding_percentage = (trace.trace_len_summary().padded_trace_len()
             - trace.trace_len_summary().trace_len())
@@ -1,11 +1,22 @@
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
+use super::data::{InputFile, OutputFile, ProgramHash, ProofBundleFile, ProofFile};
 use clap::Parser;
 use miden::{Kernel, ProgramInfo};
-use std::{path::PathBuf, time::Instant};
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 #[derive(Debug, Clone, Parser)]
-#[clap(about = "Verify a miden program")]
+#[clap(about = "Verify a miden program", disable_help_flag = true)]
 pub struct VerifyCmd {
+    /// Path to proof bundle file; if provided, inputs, outputs and proof files are not needed
+    #[clap(
+        short = 'b',
+        long = "bundle",
+        value_parser,
+        conflicts_with_all = ["input_file", "output_file", "proof_file"]
+    )]
+    bundle_file: Option<PathBuf>,
     /// Path to input file
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
@@ -13,11 +24,19 @@ pub struct VerifyCmd {
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
     /// Path to proof file
-    #[clap(short = 'p', long = "proof", value_parser)]
-    proof_file: PathBuf,
+    #[clap(
+        short = 'p',
+        long = "proof",
+        value_parser,
+        required_unless_present = "bundle_file"
+    )]
+    proof_file: Option<PathBuf>,
     /// Program hash (hex)
-    #[clap(short = 'h', long = "program-hash")]
-    program_hash: String,
+    #[clap(short = 'h', long = "program-hash", required_unless_present = "bundle_file")]
+    program_hash: Option<String>,
+    /// Print help
+    #[clap(long = "help", action = clap::ArgAction::Help)]
+    help: Option<bool>,
 }
 
 impl VerifyCmd {
@@ -26,20 +45,28 @@ impl VerifyCmd {
         println!("Verify program");
         println!("============================================================");
 
+        if let Some(bundle_path) = &self.bundle_file {
+            return self.verify_bundle(bundle_path);
+        }
+
+        // both values are required by the argument parser when no bundle is provided
+        let program_hash = self.program_hash.as_ref().expect("missing program hash");
+        let proof_file = self.proof_file.as_ref().expect("missing proof file");
+
         // read program hash from input
-        let program_hash = ProgramHash::read(&self.program_hash)?;
+        let program_hash = ProgramHash::read(program_hash)?;
 
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.proof_file)?;
+        let input_data = InputFile::read(&self.input_file, proof_file)?;
 
         // fetch the stack inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs()?;
 
         // load outputs data from file
-        let outputs_data = OutputFile::read(&self.output_file, &self.proof_file)?;
+        let outputs_data = OutputFile::read(&self.output_file, proof_file)?;
 
         // load proof from file
-        let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
+        let proof = ProofFile::read(&Some(proof_file.clone()), proof_file)?;
 
         println!("verifying program...");
         let now = Instant::now();
@@ -56,4 +83,32 @@ impl VerifyCmd {
 
         Ok(())
     }
+
+    /// Verifies the proof bundle at the specified path; if a program hash was provided, also
+    /// checks that the bundle proves the execution of this program.
+    fn verify_bundle(&self, bundle_path: &Path) -> Result<(), String> {
+        // load proof bundle from file
+        let bundle = ProofBundleFile::read(bundle_path)?;
+
+        let bundle_program_hash = *bundle.program_info().program_hash();
+        if let Some(program_hash) = &self.program_hash {
+            if ProgramHash::read(program_hash)? != bundle_program_hash {
+                return Err(format!(
+                    "Program failed verification! - the bundle proves execution of program {}",
+                    bundle_program_hash
+                ));
+            }
+        }
+
+        println!("verifying program with hash {}...", bundle_program_hash);
+        let now = Instant::now();
+
+        // verify proof
+        verifier::verify_bundle(bundle)
+            .map_err(|err| format!("Program failed verification! - {}", err))?;
+
+        println!("Verification complete in {} ms", now.elapsed().as_millis());
+
+        Ok(())
+    }
 }
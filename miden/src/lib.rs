@@ -1,6 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+pub mod recursion;
+
 // EXPORTS
 // ================================================================================================
 
@@ -11,10 +17,12 @@ pub use assembly::{
 pub use processor::{
     crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
     ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    ProgramInfo, StackInputs, TraceTable, VmState, VmStateIterator, ZERO,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
-    StackOutputs, StarkProof, Word,
+    get_output_data, math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError,
+    ProofBundle, ProvingOptions, ProvingOptionsError, StackOutputs, StarkProof, Word,
 };
-pub use verifier::{verify, VerificationError};
+#[cfg(feature = "std")]
+pub use prover::{prove_with_observer, CancellationToken, ProvingObserver, ProvingPhase};
+pub use verifier::{verify, verify_bundle, verify_output_data, VerificationError};
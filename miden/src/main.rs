@@ -23,6 +23,8 @@ pub enum Actions {
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
     Example(examples::ExampleOptions),
+    ParamSearch(cli::ParamSearchCmd),
+    ProofInfo(cli::ProofInfoCmd),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
@@ -39,6 +41,8 @@ impl Cli {
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
             Actions::Example(example) => example.execute(),
+            Actions::ParamSearch(param_search) => param_search.execute(),
+            Actions::ProofInfo(proof_info) => proof_info.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
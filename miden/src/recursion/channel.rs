@@ -0,0 +1,413 @@
+use super::{AdviceMapEntry, QuadExt};
+use air::ProcessorAir;
+use verifier::VerifierError;
+use vm_core::{
+    crypto::{
+        hash::{Rpo256, RpoDigest},
+        merkle::{MerklePath, PartialMerkleTree},
+    },
+    utils::{collections::Vec, group_vector_elements, string::ToString, IntoBytes},
+    Felt, FieldElement, StarkField, EMPTY_WORD,
+};
+use winter_air::{
+    proof::{Queries, StarkProof, Table},
+    Air,
+};
+use winter_crypto::BatchMerkleProof;
+use winter_fri::folding::fold_positions;
+
+// VERIFIER CHANNEL
+// ================================================================================================
+
+/// A view into a [StarkProof] for a computation structured to simulate an "interactive" channel.
+///
+/// Unlike the channel used by the native verifier, this channel does not check the data it reads
+/// against the commitments in the proof. Instead, it converts the proof into the shape expected
+/// by the STARK verifier in the standard library: queried values are placed into the advice map,
+/// and Merkle authentication paths are placed into partial Merkle trees.
+pub(super) struct VerifierChannel {
+    // trace queries
+    trace_roots: Vec<RpoDigest>,
+    trace_queries: Option<TraceQueries>,
+    // constraint queries
+    constraint_root: RpoDigest,
+    constraint_queries: Option<ConstraintQueries>,
+    // FRI proof
+    fri_roots: Vec<RpoDigest>,
+    fri_layer_proofs: Vec<BatchMerkleProof<Rpo256>>,
+    fri_layer_queries: Vec<Vec<QuadExt>>,
+    fri_remainder: Vec<QuadExt>,
+    // out-of-domain frame
+    ood_trace_evaluations: Vec<QuadExt>,
+    ood_constraint_evaluations: Vec<QuadExt>,
+    // query proof-of-work
+    pow_nonce: u64,
+}
+
+impl VerifierChannel {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates and returns a new [VerifierChannel] initialized from the specified `proof`.
+    pub fn new(air: &ProcessorAir, proof: StarkProof) -> Result<Self, VerifierError> {
+        let StarkProof {
+            context,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+        } = proof;
+
+        // make AIR and proof base fields are the same
+        if Felt::get_modulus_le_bytes() != context.field_modulus_bytes() {
+            return Err(VerifierError::InconsistentBaseField);
+        }
+
+        let num_trace_segments = air.trace_layout().num_segments();
+        let main_trace_width = air.trace_layout().main_trace_width();
+        let aux_trace_width = air.trace_layout().aux_trace_width();
+        let lde_domain_size = air.lde_domain_size();
+        let fri_options = air.options().to_fri_options();
+        let constraint_frame_width = air.context().num_constraint_composition_columns();
+
+        // --- parse commitments ------------------------------------------------------------------
+        let (trace_roots, constraint_root, fri_roots) = commitments
+            .parse::<Rpo256>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        // --- parse trace and constraint queries -------------------------------------------------
+        let trace_queries = TraceQueries::new(trace_queries, air)?;
+        let constraint_queries = ConstraintQueries::new(constraint_queries, air)?;
+
+        // --- parse FRI proofs -------------------------------------------------------------------
+        let fri_remainder = fri_proof
+            .parse_remainder()
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let (fri_layer_queries, fri_layer_proofs) = fri_proof
+            .parse_layers::<Rpo256, QuadExt>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        // --- parse out-of-domain evaluation frame -----------------------------------------------
+        let (ood_trace_evaluations, ood_constraint_evaluations) = ood_frame
+            .parse(main_trace_width, aux_trace_width, constraint_frame_width)
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        Ok(VerifierChannel {
+            // trace queries
+            trace_roots,
+            trace_queries: Some(trace_queries),
+            // constraint queries
+            constraint_root,
+            constraint_queries: Some(constraint_queries),
+            // FRI proof
+            fri_roots,
+            fri_layer_proofs,
+            fri_layer_queries,
+            fri_remainder,
+            // out-of-domain evaluation
+            ood_trace_evaluations,
+            ood_constraint_evaluations,
+            // query seed
+            pow_nonce,
+        })
+    }
+
+    // DATA READERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns execution trace commitments sent by the prover.
+    ///
+    /// The first commitment is for the main trace segment, and the remaining commitments are for
+    /// the auxiliary trace segments.
+    pub fn read_trace_commitments(&self) -> &[RpoDigest] {
+        &self.trace_roots
+    }
+
+    /// Returns constraint evaluation commitment sent by the prover.
+    pub fn read_constraint_commitment(&self) -> RpoDigest {
+        self.constraint_root
+    }
+
+    /// Returns trace polynomial evaluations at out-of-domain points z and z * g, where g is the
+    /// generator of the LDE domain.
+    ///
+    /// Evaluations for the current and the next rows are interleaved, and evaluations of the
+    /// auxiliary trace polynomials follow evaluations of the main trace polynomials.
+    pub fn read_ood_trace_frame(&self) -> &[QuadExt] {
+        &self.ood_trace_evaluations
+    }
+
+    /// Returns evaluations of composition polynomial columns at z^m, where z is the out-of-domain
+    /// point, and m is the number of composition polynomial columns.
+    pub fn read_ood_constraint_evaluations(&self) -> &[QuadExt] {
+        &self.ood_constraint_evaluations
+    }
+
+    /// Returns FRI layer commitments sent by the prover.
+    pub fn read_fri_layer_commitments(&self) -> &[RpoDigest] {
+        &self.fri_roots
+    }
+
+    /// Returns the coefficients of the FRI remainder polynomial sent by the prover.
+    pub fn read_fri_remainder(&self) -> &[QuadExt] {
+        &self.fri_remainder
+    }
+
+    /// Returns query proof-of-work nonce sent by the prover.
+    pub fn read_pow_nonce(&self) -> u64 {
+        self.pow_nonce
+    }
+
+    /// Returns advice map entries and partial Merkle trees for the trace states at the specified
+    /// positions of the LDE domain.
+    ///
+    /// The advice map maps the hash of each queried row to the row itself, and a partial Merkle
+    /// tree is returned for each trace segment.
+    pub fn read_queried_trace_states(
+        &mut self,
+        positions: &[usize],
+    ) -> (Vec<AdviceMapEntry>, Vec<PartialMerkleTree>) {
+        let queries = self.trace_queries.take().expect("already read");
+        let mut proofs = queries.query_proofs.into_iter();
+
+        let main_states: Vec<Vec<Felt>> = queries.main_states.rows().map(|a| a.to_vec()).collect();
+        let aux_states: Vec<Vec<Felt>> = queries
+            .aux_states
+            .as_ref()
+            .expect("Miden VM execution traces must have an auxiliary segment")
+            .rows()
+            .map(|a| QuadExt::slice_as_base_elements(a).to_vec())
+            .collect();
+
+        let (main_trace_pmt, mut advice_map) = unbatch_to_partial_mt(
+            positions,
+            main_states,
+            proofs.next().expect("missing main trace query proof"),
+        );
+        let (aux_trace_pmt, mut aux_advice_map) = unbatch_to_partial_mt(
+            positions,
+            aux_states,
+            proofs.next().expect("missing auxiliary trace query proof"),
+        );
+        advice_map.append(&mut aux_advice_map);
+
+        (advice_map, vec![main_trace_pmt, aux_trace_pmt])
+    }
+
+    /// Returns advice map entries and a partial Merkle tree for the constraint evaluations at the
+    /// specified positions of the LDE domain.
+    pub fn read_constraint_evaluations(
+        &mut self,
+        positions: &[usize],
+    ) -> (Vec<AdviceMapEntry>, PartialMerkleTree) {
+        let queries = self.constraint_queries.take().expect("already read");
+
+        let evaluations: Vec<Vec<Felt>> = queries
+            .evaluations
+            .rows()
+            .map(|a| QuadExt::slice_as_base_elements(a).to_vec())
+            .collect();
+        let (constraint_pmt, advice_map) =
+            unbatch_to_partial_mt(positions, evaluations, queries.query_proofs);
+
+        (advice_map, constraint_pmt)
+    }
+
+    /// Returns advice map entries and partial Merkle trees for the queried values of all FRI
+    /// layers, except for the remainder.
+    ///
+    /// `N` is the FRI folding factor and `domain_size` is the size of the LDE domain.
+    pub fn unbatch_fri_layers<const N: usize>(
+        &mut self,
+        positions: &[usize],
+        domain_size: usize,
+    ) -> (Vec<PartialMerkleTree>, Vec<AdviceMapEntry>) {
+        let num_layers = self.fri_roots.len() - 1;
+        let mut current_domain_size = domain_size;
+        let mut positions = positions.to_vec();
+
+        let mut advice_map = Vec::new();
+        let mut partial_trees = Vec::new();
+        let layers = self.fri_layer_proofs.drain(..).zip(self.fri_layer_queries.drain(..));
+        for (layer_proof, layer_queries) in layers.take(num_layers) {
+            let folded_positions = fold_positions(&positions, current_domain_size, N);
+
+            let unbatched_proof = layer_proof
+                .into_paths(&folded_positions)
+                .expect("failed to unbatch FRI layer proof");
+            let query_values = group_vector_elements::<QuadExt, N>(layer_queries);
+            assert_eq!(query_values.len(), unbatched_proof.len());
+
+            let (nodes, paths) = split_leaves_and_paths(unbatched_proof);
+
+            let paths = folded_positions
+                .iter()
+                .zip(nodes.iter().zip(paths))
+                .map(|(&pos, (&node, path))| (pos as u64, RpoDigest::from(node), path));
+            partial_trees
+                .push(PartialMerkleTree::with_paths(paths).expect("should not fail from paths"));
+
+            for (node, values) in nodes.into_iter().zip(query_values.iter()) {
+                let mut value = QuadExt::slice_as_base_elements(values).to_vec();
+                value.extend(EMPTY_WORD);
+                advice_map.push((node.into_bytes(), value));
+            }
+
+            positions = folded_positions;
+            current_domain_size /= N;
+        }
+
+        (partial_trees, advice_map)
+    }
+}
+
+// TRACE QUERIES
+// ================================================================================================
+
+/// Container of trace query data, including:
+/// * Queried states for all trace segments.
+/// * Merkle authentication paths for all queries.
+///
+/// Trace states for all auxiliary segments are stored in a single table.
+struct TraceQueries {
+    query_proofs: Vec<BatchMerkleProof<Rpo256>>,
+    main_states: Table<Felt>,
+    aux_states: Option<Table<QuadExt>>,
+}
+
+impl TraceQueries {
+    /// Parses the provided trace queries into trace states in the specified field and
+    /// corresponding Merkle authentication paths.
+    pub fn new(mut queries: Vec<Queries>, air: &ProcessorAir) -> Result<Self, VerifierError> {
+        assert_eq!(
+            queries.len(),
+            air.trace_layout().num_segments(),
+            "expected {} trace segment queries, but received {}",
+            air.trace_layout().num_segments(),
+            queries.len()
+        );
+
+        let num_queries = air.options().num_queries();
+
+        // parse main trace segment queries; parsing also validates that hashes of each table row
+        // form the leaves of Merkle authentication paths in the proofs
+        let main_segment_width = air.trace_layout().main_trace_width();
+        let main_segment_queries = queries.remove(0);
+        let (main_segment_query_proofs, main_segment_states) = main_segment_queries
+            .parse::<Rpo256, Felt>(air.lde_domain_size(), num_queries, main_segment_width)
+            .map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "main trace segment query deserialization failed: {err}"
+                ))
+            })?;
+
+        // all query proofs will be aggregated into a single vector
+        let mut query_proofs = vec![main_segment_query_proofs];
+
+        // parse auxiliary trace segment queries (if any), and merge resulting tables into a
+        // single table; parsing also validates that hashes of each table row form the leaves
+        // of Merkle authentication paths in the proofs
+        let aux_trace_states = if air.trace_info().is_multi_segment() {
+            let mut aux_trace_states = Vec::new();
+            for (i, segment_queries) in queries.into_iter().enumerate() {
+                let segment_width = air.trace_layout().get_aux_segment_width(i);
+                let (segment_query_proof, segment_trace_states) = segment_queries
+                    .parse::<Rpo256, QuadExt>(air.lde_domain_size(), num_queries, segment_width)
+                    .map_err(|err| {
+                        VerifierError::ProofDeserializationError(format!(
+                            "auxiliary trace segment query deserialization failed: {err}"
+                        ))
+                    })?;
+
+                query_proofs.push(segment_query_proof);
+                aux_trace_states.push(segment_trace_states);
+            }
+
+            // merge tables for each auxiliary segment into a single table
+            Some(Table::merge(aux_trace_states))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            query_proofs,
+            main_states: main_segment_states,
+            aux_states: aux_trace_states,
+        })
+    }
+}
+
+// CONSTRAINT QUERIES
+// ================================================================================================
+
+/// Container of constraint evaluation query data, including:
+/// * Queried constraint evaluation values.
+/// * Merkle authentication paths for all queries.
+struct ConstraintQueries {
+    query_proofs: BatchMerkleProof<Rpo256>,
+    evaluations: Table<QuadExt>,
+}
+
+impl ConstraintQueries {
+    /// Parses the provided constraint queries into evaluations in the specified field and
+    /// corresponding Merkle authentication paths.
+    pub fn new(queries: Queries, air: &ProcessorAir) -> Result<Self, VerifierError> {
+        let num_queries = air.options().num_queries();
+        let (query_proofs, evaluations) = queries
+            .parse::<Rpo256, QuadExt>(air.lde_domain_size(), num_queries, air.ce_blowup_factor())
+            .map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "constraint evaluation query deserialization failed: {err}"
+                ))
+            })?;
+
+        Ok(Self {
+            query_proofs,
+            evaluations,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a batch Merkle proof for the specified positions into a partial Merkle tree, and
+/// returns it together with advice map entries mapping each leaf to the queried values it commits
+/// to.
+fn unbatch_to_partial_mt(
+    positions: &[usize],
+    queries: Vec<Vec<Felt>>,
+    proof: BatchMerkleProof<Rpo256>,
+) -> (PartialMerkleTree, Vec<AdviceMapEntry>) {
+    let unbatched_proof = proof.into_paths(positions).expect("failed to unbatch query proof");
+    let (nodes, paths) = split_leaves_and_paths(unbatched_proof);
+
+    let paths = positions
+        .iter()
+        .zip(nodes.iter().zip(paths))
+        .map(|(&pos, (&node, path))| (pos as u64, RpoDigest::from(node), path));
+    let partial_tree = PartialMerkleTree::with_paths(paths).expect("should not fail from paths");
+
+    let advice_map = nodes
+        .into_iter()
+        .zip(queries)
+        .map(|(node, values)| (node.into_bytes(), values))
+        .collect();
+
+    (partial_tree, advice_map)
+}
+
+/// Splits unbatched Merkle paths, each of which starts with the leaf node, into the leaf nodes
+/// and the Merkle paths authenticating them.
+fn split_leaves_and_paths(
+    unbatched_proof: Vec<Vec<RpoDigest>>,
+) -> (Vec<[Felt; 4]>, Vec<MerklePath>) {
+    unbatched_proof
+        .into_iter()
+        .map(|mut path| {
+            let leaf = path.remove(0);
+            (<[Felt; 4]>::from(leaf), MerklePath::from(path))
+        })
+        .unzip()
+}
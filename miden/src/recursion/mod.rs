@@ -0,0 +1,369 @@
+use super::{
+    crypto::{MerkleStore, PartialMerkleTree, RandomCoin, Rpo256, RpoDigest, RpoRandomCoin},
+    math::{fft, Felt, FieldElement, StarkField},
+    AdviceInputs, ExecutionProof, FieldExtension, HashFunction, ProgramInfo, StackInputs,
+    StackOutputs,
+};
+use air::{ProcessorAir, PublicInputs};
+use core::fmt;
+use verifier::VerifierError;
+use vm_core::{stack::STACK_TOP_SIZE, utils::collections::Vec, QuadExtension, ToElements};
+use winter_air::{Air, AuxTraceRandElements};
+
+mod channel;
+use channel::VerifierChannel;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Blowup factor assumed by the STARK verifier in the standard library.
+pub const BLOWUP_FACTOR: usize = 8;
+
+/// FRI folding factor assumed by the STARK verifier in the standard library.
+pub const FRI_FOLDING_FACTOR: usize = 4;
+
+/// Maximum degree of the FRI remainder polynomial assumed by the STARK verifier in the standard
+/// library.
+pub const FRI_REMAINDER_MAX_DEGREE: usize = 7;
+
+type QuadExt = QuadExtension<Felt>;
+
+/// An entry of the advice map, i.e., a key and the values it maps to.
+pub type AdviceMapEntry = ([u8; 32], Vec<Felt>);
+
+// RECURSIVE VERIFIER INPUTS
+// ================================================================================================
+
+/// Inputs required to verify an execution proof inside Miden VM using the STARK verifier from the
+/// standard library (i.e., `std::crypto::stark::verifier::verify`).
+///
+/// The inputs consist of:
+/// - The operand stack values, which describe the parameters of the proof.
+/// - The advice stack values, which contain the public inputs, the commitments, the out-of-domain
+///   evaluations and the FRI remainder, in the order in which the verifier consumes them.
+/// - The advice map entries, which contain the queried trace states, constraint evaluations and
+///   FRI layer values, keyed by the leaves committing to them.
+/// - The partial Merkle trees authenticating the queried values against the commitments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecursiveVerifierInputs {
+    stack_values: Vec<u64>,
+    advice_stack: Vec<u64>,
+    advice_map: Vec<AdviceMapEntry>,
+    partial_trees: Vec<PartialMerkleTree>,
+}
+
+impl RecursiveVerifierInputs {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the initial operand stack values for the verifier, in the order in which they are
+    /// expected to be pushed onto the stack (i.e., the last value ends up on top of the stack).
+    ///
+    /// The values are: [grinding_factor, log(blowup_factor), num_queries, log(trace_length)].
+    pub fn stack_values(&self) -> &[u64] {
+        &self.stack_values
+    }
+
+    /// Returns the initial advice stack values for the verifier; the first value is at the top of
+    /// the advice stack.
+    pub fn advice_stack(&self) -> &[u64] {
+        &self.advice_stack
+    }
+
+    /// Returns the advice map entries required by the verifier.
+    pub fn advice_map(&self) -> &[AdviceMapEntry] {
+        &self.advice_map
+    }
+
+    /// Returns the partial Merkle trees which need to be loaded into the advice provider's Merkle
+    /// store for the verifier.
+    pub fn partial_trees(&self) -> &[PartialMerkleTree] {
+        &self.partial_trees
+    }
+
+    /// Returns the initial operand stack for the verifier.
+    pub fn stack_inputs(&self) -> StackInputs {
+        StackInputs::new(self.stack_values.iter().map(|&v| Felt::new(v)).collect())
+    }
+
+    /// Returns the advice inputs for the verifier.
+    pub fn advice_inputs(&self) -> AdviceInputs {
+        let mut store = MerkleStore::new();
+        for partial_tree in self.partial_trees.iter() {
+            store.extend(partial_tree.inner_nodes());
+        }
+
+        AdviceInputs::default()
+            .with_stack(self.advice_stack.iter().map(|&v| Felt::new(v)))
+            .with_map(self.advice_map.iter().cloned())
+            .with_merkle_store(store)
+    }
+
+    /// Consumes these inputs and returns the initial operand stack and the advice inputs for the
+    /// verifier.
+    pub fn into_parts(self) -> (StackInputs, AdviceInputs) {
+        (self.stack_inputs(), self.advice_inputs())
+    }
+}
+
+// INPUTS GENERATION
+// ================================================================================================
+
+/// Returns the inputs required to verify the specified execution proof inside Miden VM using
+/// `std::crypto::stark::verifier::verify`.
+///
+/// `program_info`, `stack_inputs` and `stack_outputs` must be the public inputs against which
+/// the proof was generated.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof was generated with parameters which are not supported by the STARK verifier in the
+///   standard library. Specifically, the proof must be generated using RPO hash function,
+///   quadratic field extension, blowup factor of 8, FRI folding factor of 4 and FRI remainder
+///   polynomial of degree at most 7.
+/// - The stack inputs or outputs do not consist of exactly 16 elements.
+/// - The proof is malformed or does not satisfy the proof-of-work requirement.
+pub fn generate_recursive_verifier_inputs(
+    proof: ExecutionProof,
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+) -> Result<RecursiveVerifierInputs, RecursionError> {
+    validate_public_inputs(&stack_inputs, &stack_outputs)?;
+
+    let (hash_fn, proof) = proof.into_parts();
+    if hash_fn != HashFunction::Rpo256 {
+        return Err(RecursionError::UnsupportedHashFunction(hash_fn));
+    }
+    validate_proof_options(proof.options())?;
+
+    // build a seed for the public coin; the initial seed is the hash of public inputs and proof
+    // context, but as the protocol progresses, the coin will be reseeded with the info received
+    // from the prover
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let pub_inputs_elements = pub_inputs.to_elements();
+    let mut public_coin_seed: Vec<Felt> = proof.context.to_elements();
+    let stack_values = vec![
+        public_coin_seed[4].as_int(),
+        public_coin_seed[5].as_int().ilog2() as u64,
+        public_coin_seed[6].as_int(),
+        public_coin_seed[7].as_int().ilog2() as u64,
+    ];
+    public_coin_seed.extend_from_slice(&pub_inputs_elements);
+
+    let mut advice_stack = to_int_vec(&pub_inputs_elements);
+
+    // create AIR instance for the computation specified in the proof
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+    let mut public_coin = RpoRandomCoin::new(&public_coin_seed);
+    let mut channel = VerifierChannel::new(&air, proof)?;
+
+    // 1 ----- trace commitment -------------------------------------------------------------------
+    let trace_commitments = channel.read_trace_commitments();
+    advice_stack.extend(digest_to_int_vec(trace_commitments));
+
+    // reseed the coin with the commitment to the main trace segment, and then draw random
+    // elements for each auxiliary segment before reseeding with its commitment
+    public_coin.reseed(trace_commitments[0]);
+    let mut aux_trace_rand_elements = AuxTraceRandElements::<QuadExt>::new();
+    for (i, commitment) in trace_commitments.iter().skip(1).enumerate() {
+        let rand_elements = air
+            .get_aux_trace_segment_random_elements(i, &mut public_coin)
+            .map_err(|_| VerifierError::RandomCoinError)?;
+        aux_trace_rand_elements.add_segment_elements(rand_elements);
+        public_coin.reseed(*commitment);
+    }
+
+    // draw random coefficients for the composition polynomial
+    air.get_constraint_composition_coefficients::<QuadExt, RpoRandomCoin>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    // 2 ----- constraint commitment --------------------------------------------------------------
+    let constraint_commitment = channel.read_constraint_commitment();
+    advice_stack.extend(digest_to_int_vec(&[constraint_commitment]));
+    public_coin.reseed(constraint_commitment);
+
+    // 3 ----- OOD frames -------------------------------------------------------------------------
+    let ood_trace_frame = channel.read_ood_trace_frame();
+    advice_stack.extend(ext_to_int_vec(ood_trace_frame));
+    public_coin.reseed(Rpo256::hash_elements(ood_trace_frame));
+
+    let ood_constraint_evaluations = channel.read_ood_constraint_evaluations();
+    advice_stack.extend(ext_to_int_vec(ood_constraint_evaluations));
+    public_coin.reseed(Rpo256::hash_elements(ood_constraint_evaluations));
+
+    // 4 ----- FRI --------------------------------------------------------------------------------
+    // the verifier expects the FRI remainder both in coefficient form and evaluated over the
+    // remainder domain
+    let fri_commitments = channel.read_fri_layer_commitments().to_vec();
+    let remainder = channel.read_fri_remainder();
+    let twiddles = fft::get_twiddles(remainder.len());
+    let remainder_evaluations =
+        fft::evaluate_poly_with_offset(remainder, &twiddles, Felt::GENERATOR, BLOWUP_FACTOR);
+
+    advice_stack.extend(digest_to_int_vec(&fri_commitments));
+    advice_stack.extend(ext_to_int_vec(remainder));
+    advice_stack.extend(ext_to_int_vec(&remainder_evaluations));
+
+    air.get_deep_composition_coefficients::<QuadExt, RpoRandomCoin>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    // reseed with FRI layer commitments, drawing a folding challenge for each layer
+    for commitment in fri_commitments.iter() {
+        public_coin.reseed(*commitment);
+        public_coin.draw::<QuadExt>().map_err(|_| VerifierError::RandomCoinError)?;
+    }
+
+    // 5 ----- trace and constraint queries -------------------------------------------------------
+    // read proof-of-work nonce sent by the prover and update the public coin with it
+    let pow_nonce = channel.read_pow_nonce();
+    advice_stack.push(pow_nonce);
+    public_coin.reseed_with_int(pow_nonce);
+
+    // make sure the proof-of-work specified by the grinding factor is satisfied
+    if public_coin.leading_zeros() < air.options().grinding_factor() {
+        return Err(VerifierError::QuerySeedProofOfWorkVerificationFailed.into());
+    }
+
+    // draw pseudo-random query positions for the LDE domain from the public coin; these are
+    // needed to build the advice map and the partial Merkle trees
+    let query_positions = public_coin
+        .draw_integers(air.options().num_queries(), air.lde_domain_size())
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    let (mut advice_map, mut partial_trees) = channel.read_queried_trace_states(&query_positions);
+    let (mut constraint_advice_map, constraint_tree) =
+        channel.read_constraint_evaluations(&query_positions);
+    let (mut fri_trees, mut fri_advice_map) =
+        channel.unbatch_fri_layers::<FRI_FOLDING_FACTOR>(&query_positions, air.lde_domain_size());
+
+    advice_map.append(&mut constraint_advice_map);
+    advice_map.append(&mut fri_advice_map);
+    partial_trees.push(constraint_tree);
+    partial_trees.append(&mut fri_trees);
+
+    Ok(RecursiveVerifierInputs {
+        stack_values,
+        advice_stack,
+        advice_map,
+        partial_trees,
+    })
+}
+
+// RECURSION ERROR
+// ================================================================================================
+
+/// Errors which can occur when generating inputs for the recursive STARK verifier.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecursionError {
+    UnsupportedBlowupFactor(usize),
+    UnsupportedFieldExtension(FieldExtension),
+    UnsupportedFriFoldingFactor(usize),
+    UnsupportedFriRemainderMaxDegree(usize),
+    UnsupportedHashFunction(HashFunction),
+    UnsupportedStackInputs(usize),
+    UnsupportedStackOutputs(usize),
+    VerifierError(VerifierError),
+}
+
+impl fmt::Display for RecursionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RecursionError::*;
+        match self {
+            UnsupportedBlowupFactor(factor) => {
+                write!(f, "blowup factor {factor} is not supported; expected {BLOWUP_FACTOR}")
+            }
+            UnsupportedFieldExtension(extension) => {
+                write!(f, "field extension {extension:?} is not supported; expected quadratic")
+            }
+            UnsupportedFriFoldingFactor(factor) => write!(
+                f,
+                "FRI folding factor {factor} is not supported; expected {FRI_FOLDING_FACTOR}"
+            ),
+            UnsupportedFriRemainderMaxDegree(degree) => write!(
+                f,
+                "FRI remainder max degree {degree} is not supported; expected {FRI_REMAINDER_MAX_DEGREE}"
+            ),
+            UnsupportedHashFunction(hash_fn) => {
+                write!(f, "hash function {hash_fn:?} is not supported; expected RPO 256")
+            }
+            UnsupportedStackInputs(len) => {
+                write!(f, "expected exactly {STACK_TOP_SIZE} stack inputs, but got {len}")
+            }
+            UnsupportedStackOutputs(len) => {
+                write!(f, "expected exactly {STACK_TOP_SIZE} stack outputs, but got {len}")
+            }
+            VerifierError(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<VerifierError> for RecursionError {
+    fn from(err: VerifierError) -> Self {
+        Self::VerifierError(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecursionError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure the public inputs can be handled by the STARK verifier in the standard library,
+/// which supports only stack inputs and outputs of exactly [STACK_TOP_SIZE] elements.
+fn validate_public_inputs(
+    stack_inputs: &StackInputs,
+    stack_outputs: &StackOutputs,
+) -> Result<(), RecursionError> {
+    let num_inputs = stack_inputs.values().len();
+    if num_inputs != STACK_TOP_SIZE {
+        return Err(RecursionError::UnsupportedStackInputs(num_inputs));
+    }
+
+    let num_outputs = stack_outputs.stack().len();
+    if num_outputs != STACK_TOP_SIZE || stack_outputs.has_overflow() {
+        return Err(RecursionError::UnsupportedStackOutputs(num_outputs));
+    }
+
+    Ok(())
+}
+
+/// Makes sure the proof was generated with parameters supported by the STARK verifier in the
+/// standard library.
+fn validate_proof_options(options: &winter_air::ProofOptions) -> Result<(), RecursionError> {
+    if options.field_extension() != FieldExtension::Quadratic {
+        return Err(RecursionError::UnsupportedFieldExtension(options.field_extension()));
+    }
+    if options.blowup_factor() != BLOWUP_FACTOR {
+        return Err(RecursionError::UnsupportedBlowupFactor(options.blowup_factor()));
+    }
+
+    let fri_options = options.to_fri_options();
+    if fri_options.folding_factor() != FRI_FOLDING_FACTOR {
+        return Err(RecursionError::UnsupportedFriFoldingFactor(fri_options.folding_factor()));
+    }
+    if fri_options.remainder_max_degree() != FRI_REMAINDER_MAX_DEGREE {
+        return Err(RecursionError::UnsupportedFriRemainderMaxDegree(
+            fri_options.remainder_max_degree(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn digest_to_int_vec(digests: &[RpoDigest]) -> Vec<u64> {
+    digests
+        .iter()
+        .flat_map(|digest| digest.as_elements())
+        .map(|e| e.as_int())
+        .collect()
+}
+
+fn ext_to_int_vec(values: &[QuadExt]) -> Vec<u64> {
+    to_int_vec(QuadExt::slice_as_base_elements(values))
+}
+
+fn to_int_vec(values: &[Felt]) -> Vec<u64> {
+    values.iter().map(|e| e.as_int()).collect()
+}
@@ -35,3 +35,261 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_prove_recursive_inputs() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    // the STARK verifier in the standard library expects exactly 16 stack inputs and outputs
+    let dir = std::env::temp_dir().join("miden_cli_prove_recursive_inputs");
+    std::fs::create_dir_all(&dir)?;
+    let program_path = dir.join("program.masm");
+    let recursive_inputs_path = dir.join("program.recursive.inputs");
+    std::fs::write(&program_path, "begin repeat.32 swap dup.1 add end end")?;
+    std::fs::write(
+        dir.join("program.inputs"),
+        r#"{ "operand_stack": ["0", "0", "0", "0", "0", "0", "0", "0",
+                               "0", "0", "0", "0", "0", "0", "1", "0"] }"#,
+    )?;
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg(&program_path)
+        .arg("-r")
+        .arg("--recursive-inputs")
+        .arg(&recursive_inputs_path);
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Writing data to input file"));
+
+    // the generated file should be a valid input file; the top four stack values are the
+    // parameters of the proof
+    let verifier_path = dir.join("verifier.masm");
+    std::fs::write(&verifier_path, "begin dropw end")?;
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run")
+        .arg("-a")
+        .arg(&verifier_path)
+        .arg("-i")
+        .arg(&recursive_inputs_path);
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Added Partial Merkle tree"))
+        .stdout(predicate::str::contains("VM cycles"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_prove_and_verify_bundle() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let dir = std::env::temp_dir().join("miden_cli_prove_and_verify_bundle");
+    std::fs::create_dir_all(&dir)?;
+    let bundle_path = dir.join("fib.bundle");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("examples/fib/fib.masm")
+        .arg("-p")
+        .arg(dir.join("fib.proof"))
+        .arg("-o")
+        .arg(dir.join("fib.outputs"))
+        .arg("-b")
+        .arg(&bundle_path);
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Writing data to proof bundle file"));
+
+    // the bundle can be verified without any other files
+    let mut cmd = bin_under_test.command();
+    cmd.arg("verify").arg("-b").arg(&bundle_path);
+    cmd.unwrap()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Verification complete"));
+
+    // verification fails if the bundle proves execution of a different program
+    let mut cmd = bin_under_test.command();
+    cmd.arg("verify").arg("-b").arg(&bundle_path).arg("-h").arg("00".repeat(32));
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Program failed verification"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_prove_custom_options() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let dir = std::env::temp_dir().join("miden_cli_prove_custom_options");
+    std::fs::create_dir_all(&dir)?;
+    let bundle_path = dir.join("fib.bundle");
+
+    // parameters of the preset can be overridden individually, and the proof can be generated
+    // by the low-memory prover
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("examples/fib/fib.masm")
+        .arg("-p")
+        .arg(dir.join("fib.proof"))
+        .arg("-o")
+        .arg(dir.join("fib.outputs"))
+        .arg("-b")
+        .arg(&bundle_path)
+        .args(["--num-queries", "20", "--blowup-factor", "16", "--grinding-factor", "0"])
+        .args(["--folding-factor", "4", "--remainder-degree", "15", "--hash-fn", "blake3-256"])
+        .arg("--low-memory");
+    cmd.unwrap().assert().stdout(
+        predicate::str::contains(
+            "Proving options: 20 queries, blowup factor 16, grinding factor 0, Quadratic field \
+            extension, FRI folding factor 4, FRI remainder max degree 15, Blake3_256 hash function",
+        )
+        .and(predicate::str::contains("Security level: at least 79 bits"))
+        .and(predicate::str::contains("Proof security level: 79 bits")),
+    );
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("verify").arg("-b").arg(&bundle_path);
+    cmd.unwrap()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Verification complete"));
+
+    // invalid parameters are rejected before proving
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("examples/fib/fib.masm")
+        .args(["--blowup-factor", "4"]);
+    cmd.unwrap().assert().stdout(
+        predicate::str::contains("Invalid proving options")
+            .and(predicate::str::contains("Proving options:").not()),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cli_proof_info() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let dir = std::env::temp_dir().join("miden_cli_proof_info");
+    std::fs::create_dir_all(&dir)?;
+    let proof_path = dir.join("fib.proof");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("examples/fib/fib.masm")
+        .arg("-p")
+        .arg(&proof_path)
+        .arg("-o")
+        .arg(dir.join("fib.outputs"));
+    cmd.unwrap().assert().stdout(predicate::str::contains("Creating proof file"));
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("proof-info").arg(&proof_path);
+    cmd.unwrap().assert().success().stdout(
+        predicate::str::contains("Hash function:            Blake3_192")
+            .and(predicate::str::contains("Number of queries:        27"))
+            .and(predicate::str::contains("Security level:           96 bits")),
+    );
+
+    let proof_size = std::fs::metadata(&proof_path)?.len();
+    let mut cmd = bin_under_test.command();
+    cmd.arg("proof-info").arg(&proof_path).arg("--json");
+    cmd.unwrap().assert().success().stdout(
+        predicate::str::starts_with("{")
+            .and(predicate::str::contains("\"hash_fn\": \"Blake3_192\""))
+            .and(predicate::str::contains("\"security_level\": 96"))
+            .and(predicate::str::contains(format!("\"total\": {proof_size}"))),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cli_param_search() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("param-search")
+        .arg("-s")
+        .arg("100")
+        .arg("-t")
+        .arg("1000000")
+        .arg("-n")
+        .arg("5");
+    cmd.unwrap().assert().success().stdout(
+        predicate::str::contains("at least 100 bits of security for traces of 1048576 rows")
+            .and(predicate::str::contains("Blake3_256"))
+            .and(predicate::str::contains("128-bit size-optimized")),
+    );
+
+    // the hash function bounds the achievable security level
+    let mut cmd = bin_under_test.command();
+    cmd.arg("param-search").arg("-s").arg("128").arg("--hash-fn").arg("blake3-192");
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("provides at most 96 bits of security"));
+
+    // size-optimized proofs are smaller than the default ones
+    let dir = std::env::temp_dir().join("miden_cli_param_search");
+    std::fs::create_dir_all(&dir)?;
+    let mut proof_sizes = Vec::new();
+    for args in [&[][..], &["--size-optimized"][..]] {
+        let proof_path = dir.join("fib.proof");
+        let mut cmd = bin_under_test.command();
+        cmd.arg("prove")
+            .arg("-a")
+            .arg("examples/fib/fib.masm")
+            .arg("-p")
+            .arg(&proof_path)
+            .arg("-o")
+            .arg(dir.join("fib.outputs"))
+            .args(args);
+        cmd.unwrap()
+            .assert()
+            .stdout(predicate::str::contains("Proof security level: 96 bits"));
+        proof_sizes.push(std::fs::metadata(&proof_path)?.len());
+    }
+    assert!(proof_sizes[1] < proof_sizes[0]);
+
+    Ok(())
+}
@@ -5,6 +5,11 @@ mod cli;
 mod exec_iters;
 mod flow_control;
 mod operations;
+mod output_commitments;
+mod proof_bundle;
+mod proving_observer;
+mod proving_options;
+mod recursion;
 
 // TESTS
 // ================================================================================================
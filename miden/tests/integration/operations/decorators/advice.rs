@@ -32,6 +32,34 @@ fn advice_push_u64div() {
     test.expect_stack(&expected);
 }
 
+#[test]
+fn advice_push_udiv() {
+    // push a/b onto the advice stack for a 128-bit dividend and a 64-bit divisor, and then move
+    // these values onto the operand stack.
+    let source = "begin adv.push_udiv.4.2 adv_push.6 end";
+
+    let a = ((rand_value::<u64>() as u128) << 64) | rand_value::<u64>() as u128;
+    let b = rand_value::<u64>() as u128;
+    let q = a / b;
+    let r = a % b;
+
+    let a_limbs = split_u128(a);
+    let b_limbs = [(b >> 32) as u64, b as u32 as u64];
+    let q_limbs = split_u128(q);
+    let r_limbs = [(r >> 32) as u64, r as u32 as u64];
+
+    let mut inputs = a_limbs.iter().rev().copied().collect::<Vec<_>>();
+    inputs.extend(b_limbs.iter().rev());
+
+    let mut expected = r_limbs.to_vec();
+    expected.extend(q_limbs);
+    expected.extend(b_limbs);
+    expected.extend(a_limbs);
+
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&expected);
+}
+
 #[test]
 fn advice_push_u64div_repeat() {
     // This procedure repeats the following steps 7 times:
@@ -338,3 +366,13 @@ fn key_to_bytes(key: [u64; 4]) -> [u8; 32] {
 
     result
 }
+
+/// Splits the provided u128 value into 32-bit limbs, with the most significant limb first.
+fn split_u128(value: u128) -> [u64; 4] {
+    [
+        (value >> 96) as u32 as u64,
+        (value >> 64) as u32 as u64,
+        (value >> 32) as u32 as u64,
+        value as u32 as u64,
+    ]
+}
@@ -0,0 +1,66 @@
+use assembly::Assembler;
+use miden::{
+    get_output_data, prove, verify, verify_output_data, DefaultHost, Digest, ProgramInfo,
+    ProvingOptions, StackInputs, VerificationError,
+};
+use processor::crypto::Rpo256;
+use stdlib::StdLibrary;
+use vm_core::{Felt, ZERO};
+
+/// Writes the squares of the first 100 integers into memory starting at address 1000, one square
+/// per word, and commits to them as the public outputs of the program.
+const SOURCE: &str = "
+    use.std::sys
+
+    begin
+        push.0
+        repeat.100
+            dup dup mul push.0.0.0
+            dup.4 add.1000 mem_storew dropw
+            add.1
+        end
+        drop
+
+        push.1100 push.1000
+        exec.sys::commit_outputs
+        exec.sys::truncate_stack
+    end";
+
+#[test]
+fn output_data_commitment() {
+    let program = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .unwrap()
+        .compile(SOURCE)
+        .unwrap();
+    let stack_inputs = StackInputs::default();
+
+    // the host is passed by reference so that the output data can be retrieved after proving
+    let mut host = DefaultHost::default();
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), &mut host, ProvingOptions::default()).unwrap();
+    let data = get_output_data(&stack_outputs, host.advice_provider()).unwrap().to_vec();
+
+    let expected = (0..100_u64)
+        .flat_map(|i| [Felt::new(i * i), ZERO, ZERO, ZERO])
+        .collect::<Vec<_>>();
+    assert_eq!(data, expected);
+
+    // the commitment to the data is a part of the public outputs
+    let commitment = Digest::from(stack_outputs.get_stack_word(0).unwrap());
+    assert_eq!(commitment, Rpo256::hash_elements(&expected));
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, stack_outputs.clone(), proof).is_ok());
+    assert_eq!(verify_output_data(&stack_outputs, &data), Ok(()));
+
+    // tampered, truncated, or misaligned data is rejected
+    let mut tampered = data.clone();
+    tampered[4] = Felt::new(2);
+    for invalid in [&tampered[..], &data[..396], &data[..398], &[]] {
+        assert_eq!(
+            verify_output_data(&stack_outputs, invalid),
+            Err(VerificationError::OutputCommitmentMismatch)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use assembly::Assembler;
+use miden::{
+    prove, verify_bundle, DefaultHost, Digest, ProgramInfo, ProofBundle, ProvingOptions,
+    ProvingOptionsError, StackInputs, StackOutputs, VerificationError,
+};
+use processor::ExecutionOptions;
+use test_utils::serde::DeserializationError;
+
+#[test]
+fn proof_bundle_serialization() {
+    let bundle = prove_bundle(ProvingOptions::default());
+    let bytes = bundle.to_bytes();
+    assert_eq!(bytes[0], ProofBundle::VERSION);
+
+    let deserialized = ProofBundle::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, bundle);
+    assert_eq!(verify_bundle(deserialized), Ok(bundle.proof().security_level()));
+
+    // deterministic and low-memory proving affect only how the proof is generated, and are not
+    // serialized
+    let (program_info, stack_inputs, stack_outputs, options, proof) = bundle.clone().into_parts();
+    let options = options.with_deterministic_proving().with_low_memory_proving();
+    let other =
+        ProofBundle::new(program_info, stack_inputs, stack_outputs, options, proof).unwrap();
+    assert_eq!(other.to_bytes(), bytes);
+    assert_eq!(other, bundle);
+
+    // execution options are preserved as well
+    let exec_options = ExecutionOptions::new(Some(1 << 20), 128)
+        .unwrap()
+        .with_max_call_cycles(64)
+        .with_call_cycle_limit(Digest::default(), 16);
+    let options = ProvingOptions::with_96_bit_security(true).with_execution_options(exec_options);
+    let bundle = prove_bundle(options.clone());
+    let deserialized = ProofBundle::from_bytes(&bundle.to_bytes()).unwrap();
+    assert_eq!(deserialized.options(), &options);
+    assert!(verify_bundle(deserialized).is_ok());
+}
+
+#[test]
+fn proof_bundle_invalid() {
+    let bundle = prove_bundle(ProvingOptions::default());
+
+    // unsupported version
+    let mut bytes = bundle.to_bytes();
+    bytes[0] = ProofBundle::VERSION + 1;
+    assert!(matches!(
+        ProofBundle::from_bytes(&bytes),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+
+    // trailing bytes
+    let mut bytes = bundle.to_bytes();
+    bytes.push(0);
+    assert_eq!(ProofBundle::from_bytes(&bytes), Err(DeserializationError::UnconsumedBytes));
+
+    // truncated bundle
+    let bytes = bundle.to_bytes();
+    assert!(ProofBundle::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // wrong stack outputs
+    let (program_info, stack_inputs, _, options, proof) = bundle.into_parts();
+    let stack_outputs = StackOutputs::new(vec![4], vec![]).unwrap();
+    let bundle =
+        ProofBundle::new(program_info, stack_inputs, stack_outputs, options, proof).unwrap();
+    assert!(matches!(verify_bundle(bundle), Err(VerificationError::VerifierError(_))));
+}
+
+#[test]
+fn proof_bundle_inconsistent_options() {
+    let (program_info, stack_inputs, stack_outputs, _, proof) =
+        prove_bundle(ProvingOptions::default()).into_parts();
+    // the proof was generated using BLAKE3 with 192-bit output
+    let options = ProvingOptions::with_128_bit_security(false);
+    assert_eq!(
+        ProofBundle::new(program_info, stack_inputs, stack_outputs, options, proof),
+        Err(ProvingOptionsError::InconsistentWithProof)
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_bundle(options: ProvingOptions) -> ProofBundle {
+    let program = Assembler::default().compile("begin push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_values([2]).unwrap();
+
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), DefaultHost::default(), options.clone()).unwrap();
+
+    ProofBundle::new(ProgramInfo::from(program), stack_inputs, stack_outputs, options, proof)
+        .unwrap()
+}
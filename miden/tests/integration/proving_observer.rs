@@ -0,0 +1,126 @@
+use assembly::Assembler;
+use miden::{
+    prove_with_observer, verify, CancellationToken, DefaultHost, ExecutionError, ProgramInfo,
+    ProvingObserver, ProvingOptions, ProvingPhase, StackInputs,
+};
+
+const SOURCE: &str = "begin repeat.100 dup add end end";
+
+const PHASES: [ProvingPhase; 6] = [
+    ProvingPhase::Execution,
+    ProvingPhase::MainTraceCommitment,
+    ProvingPhase::AuxTraceCommitment,
+    ProvingPhase::ConstraintEvaluation,
+    ProvingPhase::ConstraintCommitment,
+    ProvingPhase::Fri,
+];
+
+#[test]
+fn prove_with_observer_reports_phases() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+
+    // the proof is always generated by the low-memory prover, and thus, the phases are reported
+    // in the same way regardless of whether low-memory proving is requested
+    for options in [ProvingOptions::default(), ProvingOptions::default().with_low_memory_proving()]
+    {
+        let mut observer = RecordingObserver::default();
+        let (stack_outputs, proof) = prove_with_observer(
+            &program,
+            stack_inputs.clone(),
+            DefaultHost::default(),
+            options,
+            &mut observer,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+
+        // every phase is started and finished in order, with increasing progress
+        assert!(PHASES.windows(2).all(|pair| pair[0].progress() < pair[1].progress()));
+        let expected = PHASES
+            .iter()
+            .flat_map(|&phase| [Event::Started(phase), Event::Finished(phase, phase.progress())])
+            .collect::<Vec<_>>();
+        assert_eq!(observer.events, expected);
+        assert_eq!(ProvingPhase::Fri.progress(), 100);
+
+        let program_info = ProgramInfo::from(program.clone());
+        assert!(verify(program_info, stack_inputs.clone(), stack_outputs, proof).is_ok());
+    }
+}
+
+#[test]
+fn prove_with_observer_cancellation() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+
+    // proving does not start if the token has already been cancelled
+    let token = CancellationToken::new();
+    token.cancel();
+    let mut observer = RecordingObserver::default();
+    let result = prove_with_observer(
+        &program,
+        stack_inputs.clone(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+        &mut observer,
+        &token,
+    );
+    assert!(matches!(result, Err(ExecutionError::ProvingCancelled)));
+    assert!(observer.events.is_empty());
+
+    // cancellation requested during a phase takes effect before the next phase starts
+    for options in [ProvingOptions::default(), ProvingOptions::default().with_low_memory_proving()]
+    {
+        for (i, &phase) in PHASES.iter().enumerate().take(PHASES.len() - 1) {
+            let token = CancellationToken::new();
+            let mut observer = RecordingObserver {
+                cancel_on: Some((phase, token.clone())),
+                ..Default::default()
+            };
+            let result = prove_with_observer(
+                &program,
+                stack_inputs.clone(),
+                DefaultHost::default(),
+                options.clone(),
+                &mut observer,
+                &token,
+            );
+            assert!(matches!(result, Err(ExecutionError::ProvingCancelled)));
+            assert_eq!(observer.events.len(), 2 * (i + 1));
+            assert_eq!(observer.events.last(), Some(&Event::Finished(phase, phase.progress())));
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    Started(ProvingPhase),
+    Finished(ProvingPhase, u8),
+}
+
+/// Records the events reported during proof generation, and cancels the specified token when the
+/// specified phase starts.
+#[derive(Default)]
+struct RecordingObserver {
+    events: Vec<Event>,
+    cancel_on: Option<(ProvingPhase, CancellationToken)>,
+}
+
+impl ProvingObserver for RecordingObserver {
+    fn on_phase_started(&mut self, phase: ProvingPhase) {
+        self.events.push(Event::Started(phase));
+        if let Some((cancel_phase, token)) = &self.cancel_on {
+            if *cancel_phase == phase {
+                token.cancel();
+            }
+        }
+    }
+
+    fn on_phase_finished(&mut self, phase: ProvingPhase, progress: u8) {
+        self.events.push(Event::Finished(phase, progress));
+    }
+}
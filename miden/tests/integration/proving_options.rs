@@ -0,0 +1,151 @@
+use assembly::Assembler;
+use miden::{
+    prove, verify, DefaultHost, FieldExtension, HashFunction, ProgramInfo, ProvingOptions,
+    ProvingOptionsError, StackInputs,
+};
+
+#[test]
+fn proving_options_validation() {
+    let options =
+        ProvingOptions::try_new(27, 8, 16, FieldExtension::Quadratic, 8, 255, HashFunction::Rpo256);
+    assert_eq!(
+        options,
+        Ok(ProvingOptions::new(
+            27,
+            8,
+            16,
+            FieldExtension::Quadratic,
+            8,
+            255,
+            HashFunction::Rpo256
+        ))
+    );
+
+    let try_new =
+        |num_queries, blowup_factor, grinding_factor, folding_factor, remainder_degree| {
+            ProvingOptions::try_new(
+                num_queries,
+                blowup_factor,
+                grinding_factor,
+                FieldExtension::Quadratic,
+                folding_factor,
+                remainder_degree,
+                HashFunction::Blake3_192,
+            )
+        };
+    assert_eq!(try_new(0, 8, 16, 8, 255), Err(ProvingOptionsError::InvalidNumQueries(0)));
+    assert_eq!(try_new(256, 8, 16, 8, 255), Err(ProvingOptionsError::InvalidNumQueries(256)));
+    assert_eq!(try_new(27, 12, 16, 8, 255), Err(ProvingOptionsError::InvalidBlowupFactor(12)));
+    assert_eq!(try_new(27, 256, 16, 8, 255), Err(ProvingOptionsError::InvalidBlowupFactor(256)));
+    assert_eq!(try_new(27, 4, 16, 8, 255), Err(ProvingOptionsError::BlowupFactorTooSmall(8, 4)));
+    assert_eq!(try_new(27, 8, 33, 8, 255), Err(ProvingOptionsError::InvalidGrindingFactor(33)));
+    assert_eq!(try_new(27, 8, 16, 6, 255), Err(ProvingOptionsError::InvalidFriFoldingFactor(6)));
+    assert_eq!(
+        try_new(27, 8, 16, 32, 255),
+        Err(ProvingOptionsError::InvalidFriFoldingFactor(32))
+    );
+    assert_eq!(
+        try_new(27, 8, 16, 8, 100),
+        Err(ProvingOptionsError::InvalidFriRemainderMaxDegree(100))
+    );
+}
+
+#[test]
+fn proving_options_security_level() {
+    // the security level predicted from the options matches the security level of the proof
+    let program = Assembler::default().compile("begin repeat.100 dup add end end").unwrap();
+    for options in [
+        ProvingOptions::with_96_bit_security(false),
+        ProvingOptions::with_128_bit_security(true),
+        ProvingOptions::new(20, 16, 0, FieldExtension::Quadratic, 4, 15, HashFunction::Blake3_256),
+        // bounded by the size of the base field
+        ProvingOptions::new(27, 8, 16, FieldExtension::None, 8, 31, HashFunction::Rpo256),
+        // query security below the grinding floor, so grinding does not contribute
+        ProvingOptions::new(20, 8, 16, FieldExtension::Quadratic, 4, 31, HashFunction::Blake3_256),
+        // query security exactly at the grinding floor
+        ProvingOptions::new(20, 16, 8, FieldExtension::Cubic, 4, 7, HashFunction::Blake3_192),
+    ] {
+        let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+        let (_, proof) =
+            prove(&program, stack_inputs, DefaultHost::default(), options.clone()).unwrap();
+        let trace_len = proof.stark_proof().get_trace_info().length();
+        assert_eq!(options.security_level(trace_len), proof.security_level());
+    }
+
+    // the security level is bounded by the size of the field for long traces
+    let options = ProvingOptions::with_96_bit_security(false);
+    assert_eq!(options.security_level(1 << 10), 96);
+    assert_eq!(options.security_level(1 << 32), 95);
+
+    // and by the collision resistance of the hash function
+    let options =
+        ProvingOptions::new(64, 8, 0, FieldExtension::Cubic, 4, 7, HashFunction::Blake3_192);
+    assert_eq!(options.security_level(1 << 10), 96);
+}
+
+#[test]
+fn proving_options_proof_size_estimate() {
+    // the estimated proof size is close to the actual size of the proof
+    let program = Assembler::default().compile("begin repeat.1000 dup add end end").unwrap();
+    for options in [
+        ProvingOptions::with_96_bit_security(true),
+        ProvingOptions::with_128_bit_security(false),
+        ProvingOptions::new(
+            16,
+            32,
+            20,
+            FieldExtension::Quadratic,
+            16,
+            31,
+            HashFunction::Blake3_192,
+        ),
+    ] {
+        let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+        let (_, proof) =
+            prove(&program, stack_inputs, DefaultHost::default(), options.clone()).unwrap();
+        let trace_len = proof.stark_proof().get_trace_info().length();
+        let actual = proof.to_bytes().len();
+        let estimate = options.estimate_proof_size(trace_len);
+        assert!(
+            actual.abs_diff(estimate) * 20 < actual,
+            "actual: {actual}, estimate: {estimate}"
+        );
+    }
+
+    // proofs get larger with the length of the trace
+    let options = ProvingOptions::default();
+    assert!(options.estimate_proof_size(1 << 20) > options.estimate_proof_size(1 << 10));
+}
+
+#[test]
+fn proving_options_size_optimized_presets() {
+    // size-optimized presets provide the same security as the default presets with smaller proofs
+    let presets = [
+        (
+            ProvingOptions::with_96_bit_security(false),
+            ProvingOptions::with_size_optimized_96_bit_security(),
+        ),
+        (
+            ProvingOptions::with_128_bit_security(false),
+            ProvingOptions::with_size_optimized_128_bit_security(),
+        ),
+    ];
+    for (default, size_optimized) in presets {
+        for trace_len in [1 << 10, 1 << 20] {
+            assert_eq!(default.security_level(trace_len), size_optimized.security_level(trace_len));
+            assert!(
+                size_optimized.estimate_proof_size(trace_len)
+                    < default.estimate_proof_size(trace_len)
+            );
+        }
+    }
+
+    // proofs generated with size-optimized presets are valid
+    let program = Assembler::default().compile("begin repeat.100 dup add end end").unwrap();
+    let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+    let options = ProvingOptions::with_size_optimized_128_bit_security();
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    assert_eq!(proof.security_level(), 128);
+    assert!(verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof).is_ok());
+}
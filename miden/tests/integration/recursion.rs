@@ -0,0 +1,133 @@
+use assembly::Assembler;
+use miden::{
+    prove,
+    recursion::{generate_recursive_verifier_inputs, RecursionError},
+    DefaultHost, ExecutionProof, FieldExtension, HashFunction, ProgramInfo, ProvingOptions,
+    StackInputs, StackOutputs,
+};
+use stdlib::StdLibrary;
+use test_utils::{stack_to_ints, Test};
+
+// Note: Changes to MidenVM may cause these tests to fail when some of the assumptions documented
+// in `stdlib/asm/crypto/stark/verifier.masm` are violated.
+
+// An example MASM program to be verified inside Miden VM. Note that output stack-overflow is not
+// yet supported because of the way public inputs are handled by the STARK verifier. Thus the
+// output stack should be of size exactly 16.
+const EXAMPLE_SOURCE: &str = "
+    begin
+        repeat.32
+            swap dup.1 add
+        end
+    end";
+
+#[test]
+fn recursive_verifier_inputs() {
+    let options = recursive_proving_options();
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_example(options);
+    let trace_len = proof.stark_proof().get_trace_info().length();
+
+    let inputs = generate_recursive_verifier_inputs(
+        proof,
+        program_info,
+        stack_inputs.clone(),
+        stack_outputs.clone(),
+    )
+    .unwrap();
+
+    // the operand stack describes the proof parameters
+    let expected_stack = [12, 3, 43, trace_len.ilog2() as u64];
+    assert_eq!(inputs.stack_values(), &expected_stack);
+
+    // the advice stack starts with the public inputs: program hash, kernel, stack inputs and
+    // outputs
+    let num_public_inputs = 4 + 16 + 16;
+    assert_eq!(&inputs.advice_stack()[4..20], &stack_to_ints(stack_inputs.values()));
+    assert_eq!(&inputs.advice_stack()[20..num_public_inputs], stack_outputs.stack());
+
+    // there are two partial Merkle trees for the trace, one for the constraint evaluations, and
+    // at least one for the FRI layers
+    assert!(inputs.partial_trees().len() >= 4);
+    assert!(!inputs.advice_map().is_empty());
+
+    let advice_stack_len = inputs.advice_stack().len();
+    let (verifier_stack_inputs, advice_inputs) = inputs.into_parts();
+    assert_eq!(verifier_stack_inputs.values().len(), expected_stack.len());
+    assert_eq!(advice_inputs.stack().len(), advice_stack_len);
+}
+
+#[test]
+fn recursive_verifier_inputs_unsupported_proof() {
+    // proofs generated with BLAKE3 cannot be verified inside the VM
+    let options =
+        ProvingOptions::new(27, 8, 16, FieldExtension::Quadratic, 4, 7, HashFunction::Blake3_192);
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_example(options);
+    let result =
+        generate_recursive_verifier_inputs(proof, program_info, stack_inputs, stack_outputs);
+    assert_eq!(result, Err(RecursionError::UnsupportedHashFunction(HashFunction::Blake3_192)));
+
+    // proofs generated with a folding factor other than 4 cannot be verified inside the VM
+    let options =
+        ProvingOptions::new(27, 8, 16, FieldExtension::Quadratic, 8, 7, HashFunction::Rpo256);
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_example(options);
+    let result =
+        generate_recursive_verifier_inputs(proof, program_info, stack_inputs, stack_outputs);
+    assert_eq!(result, Err(RecursionError::UnsupportedFriFoldingFactor(8)));
+
+    // stack inputs must consist of exactly 16 elements
+    let (program_info, _, stack_outputs, proof) = prove_example(recursive_proving_options());
+    let stack_inputs = StackInputs::try_from_values([0, 1]).unwrap();
+    let result =
+        generate_recursive_verifier_inputs(proof, program_info, stack_inputs, stack_outputs);
+    assert_eq!(result, Err(RecursionError::UnsupportedStackInputs(2)));
+}
+
+#[test]
+#[ignore]
+fn stark_verifier_e2f4() {
+    let (program_info, stack_inputs, stack_outputs, proof) =
+        prove_example(recursive_proving_options());
+    let inputs =
+        generate_recursive_verifier_inputs(proof, program_info, stack_inputs, stack_outputs)
+            .unwrap();
+    let (stack_inputs, advice_inputs) = inputs.into_parts();
+
+    // verify inside Miden VM
+    let source = "
+        use.std::crypto::stark::verifier
+        begin
+            exec.verifier::verify
+        end
+        ";
+
+    let test = Test {
+        source: source.to_string(),
+        kernel: None,
+        stack_inputs,
+        advice_inputs,
+        in_debug_mode: false,
+        libraries: vec![StdLibrary::default().into()],
+    };
+    test.expect_stack(&[]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn recursive_proving_options() -> ProvingOptions {
+    ProvingOptions::new(43, 8, 12, FieldExtension::Quadratic, 4, 7, HashFunction::Rpo256)
+}
+
+fn prove_example(
+    options: ProvingOptions,
+) -> (ProgramInfo, StackInputs, StackOutputs, ExecutionProof) {
+    let program = Assembler::default().compile(EXAMPLE_SOURCE).unwrap();
+    let mut stack_inputs = vec![0_u64; 16];
+    stack_inputs[14] = 1;
+    let stack_inputs = StackInputs::try_from_values(stack_inputs).unwrap();
+    let host = DefaultHost::default();
+
+    let (stack_outputs, proof) = prove(&program, stack_inputs.clone(), host, options).unwrap();
+
+    (ProgramInfo::from(program), stack_inputs, stack_outputs, proof)
+}
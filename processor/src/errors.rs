@@ -4,7 +4,10 @@ use super::{
     CodeBlock, Digest, Felt, QuadFelt, Word,
 };
 use core::fmt::{Display, Formatter};
-use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{string::String, to_hex},
+};
 use winter_prover::{math::FieldElement, ProverError};
 
 #[cfg(feature = "std")]
@@ -18,10 +21,13 @@ pub enum ExecutionError {
     AdviceMapKeyNotFound(Word),
     AdviceMapValueInvalidLength(Word, usize, usize),
     AdviceStackReadFailed(u32),
+    AdviceStorageFailed(String),
+    CallCycleLimitExceeded(u32, Digest, u32),
     CallerNotInSyscall,
     CodeBlockNotFound(Digest),
     DynamicCodeBlockNotFound(Digest),
     CycleLimitExceeded(u32),
+    ExecutionCancelled(u32),
     DivideByZero(u32),
     Ext2InttError(Ext2InttError),
     FailedAssertion(u32, Felt),
@@ -40,7 +46,9 @@ pub enum ExecutionError {
     NotBinaryValue(Felt),
     NotU32Value(Felt, Felt),
     ProverError(ProverError),
+    ProvingCancelled,
     SyscallTargetNotInKernel(Digest),
+    UnsupportedProvingOptions(&'static str),
     UnexecutableCodeBlock(CodeBlock),
     MalformedSignatureKey(&'static str),
     FailedSignatureGeneration(&'static str),
@@ -63,6 +71,16 @@ impl Display for ExecutionError {
                 )
             }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            AdviceStorageFailed(reason) => {
+                write!(f, "Advice provider storage backend failed: {reason}")
+            }
+            CallCycleLimitExceeded(ctx, callee, max_cycles) => {
+                let hex = to_hex(&callee.as_bytes())?;
+                write!(
+                    f,
+                    "Call to procedure with root {hex} in context {ctx} exceeded the allowed number of cycles (max call cycles = {max_cycles})"
+                )
+            }
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
@@ -83,6 +101,9 @@ impl Display for ExecutionError {
             CycleLimitExceeded(max_cycles) => {
                 write!(f, "Exceeded the allowed number of cycles (max cycles = {max_cycles})")
             }
+            ExecutionCancelled(clk) => {
+                write!(f, "Execution was cancelled by the host at clock cycle {clk}")
+            }
             DivideByZero(clk) => write!(f, "Division by zero at clock cycle {clk}"),
             Ext2InttError(err) => write!(f, "Failed to execute Ext2Intt operation: {err}"),
             FailedAssertion(clk, err_code) => {
@@ -137,6 +158,10 @@ impl Display for ExecutionError {
                 )
             }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
+            ProvingCancelled => write!(f, "Proof generation was cancelled"),
+            UnsupportedProvingOptions(reason) => {
+                write!(f, "Proof generation failed: unsupported proving options - {reason}")
+            }
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
@@ -0,0 +1,485 @@
+use super::{
+    injectors, AdviceInputs, AdviceProvider, AdviceSource, BTreeMap, ExecutionError, Felt,
+    InnerNodeInfo, IntoBytes, MerklePath, MerkleStore, NodeIndex, RpoDigest, StarkField, Vec, Word,
+};
+use crate::ProcessState;
+use core::borrow::Borrow;
+use std::{io, path::Path};
+use vm_core::{
+    crypto::{
+        hash::Rpo256,
+        merkle::{EmptySubtreeRoots, MerkleError},
+    },
+    utils::collections::BTreeSet,
+    SignatureKind,
+};
+
+mod storage;
+use storage::AdviceStorage;
+
+#[cfg(test)]
+mod tests;
+
+// DISK ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] implementation which keeps its Merkle store in a file on the local disk.
+///
+/// Internal Merkle nodes are loaded lazily from the file whenever the VM requests a node or a
+/// path, and nodes created during execution (e.g., via `UpdateMerkleNode` or `SmtSet` injectors)
+/// are appended to the file. Thus, only an index mapping the nodes to their offsets in the file
+/// is kept in memory instead of the nodes themselves. The index is a `BTreeMap` keyed by a prefix
+/// of each node digest; including the overhead of the map, it takes up several times less memory
+/// than the nodes, but still grows linearly with the number of nodes.
+///
+/// The advice map is persisted to the same file, but is loaded into memory in its entirety when
+/// the file is opened, and thus, is limited by the available memory. The advice stack is not
+/// persisted.
+///
+/// Newly inserted data is buffered and written to disk in batches; [DiskAdviceProvider::flush()]
+/// can be used to make sure all data has been written to the file.
+pub struct DiskAdviceProvider {
+    stack: Vec<Felt>,
+    map: BTreeMap<[u8; 32], Vec<Felt>>,
+    storage: AdviceStorage,
+    empty_nodes: BTreeMap<RpoDigest, RpoDigest>,
+}
+
+impl DiskAdviceProvider {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens the advice storage file at the specified path (creating it if it does not exist) and
+    /// adds the provided advice inputs to it.
+    ///
+    /// The advice stack of the returned provider is initialized with the stack of the provided
+    /// inputs, while the advice map and Merkle store entries of the inputs are merged with the
+    /// data already contained in the file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened, its contents are malformed, or the inputs
+    /// could not be written into it.
+    pub fn open<P: AsRef<Path>>(path: P, inputs: AdviceInputs) -> io::Result<Self> {
+        let (storage, map) = AdviceStorage::open(path)?;
+
+        // the store does not need to persist the nodes of empty subtrees as these can be computed
+        // on the fly; we keep them in memory, mapping each parent to its (identical) children
+        let subtrees = EmptySubtreeRoots::empty_hashes(255);
+        let empty_nodes = subtrees.iter().copied().zip(subtrees.iter().skip(1).copied()).collect();
+
+        let mut provider = Self {
+            stack: Vec::new(),
+            map,
+            storage,
+            empty_nodes,
+        };
+
+        let (mut stack, map, store) = inputs.into_parts();
+        stack.reverse();
+        provider.stack = stack;
+
+        for (key, values) in map {
+            provider.storage.insert_map_entry(key, &values)?;
+            provider.map.insert(key, values);
+        }
+
+        for InnerNodeInfo { value, left, right } in store.inner_nodes() {
+            if !provider.empty_nodes.contains_key(&value) {
+                provider.storage.insert_node(value, left, right)?;
+            }
+        }
+
+        Ok(provider)
+    }
+
+    // PUBLIC METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current state of the advice stack.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    /// Returns true if a Merkle tree with the specified root exists in the store of this advice
+    /// provider.
+    pub fn has_merkle_root(&self, root: RpoDigest) -> Result<bool, ExecutionError> {
+        self.get_children(root).map(|children| children.is_some())
+    }
+
+    /// Writes all buffered data to the underlying file.
+    ///
+    /// # Errors
+    /// Returns an error if the data could not be written.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.storage.flush()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the children of the specified internal node, or None if the node is either a leaf
+    /// or is not known to this provider.
+    fn get_children(
+        &self,
+        node: RpoDigest,
+    ) -> Result<Option<(RpoDigest, RpoDigest)>, ExecutionError> {
+        if let Some(&child) = self.empty_nodes.get(&node) {
+            return Ok(Some((child, child)));
+        }
+        self.storage.get_node(&node).map_err(storage_error)
+    }
+
+    /// Adds an internal node with the specified children to the Merkle store.
+    fn insert_node(
+        &mut self,
+        node: RpoDigest,
+        left: RpoDigest,
+        right: RpoDigest,
+    ) -> Result<(), ExecutionError> {
+        if self.empty_nodes.contains_key(&node) {
+            return Ok(());
+        }
+        self.storage.insert_node(node, left, right).map_err(storage_error)
+    }
+
+    /// Returns the value of the node at the specified index of the tree with the specified root,
+    /// together with the path to that node.
+    fn get_value_path(
+        &self,
+        root: RpoDigest,
+        index: NodeIndex,
+    ) -> Result<(RpoDigest, MerklePath), ExecutionError> {
+        let mut hash = root;
+        let mut path = Vec::with_capacity(index.depth().into());
+
+        // corner case: check the root is in the store when called with index `NodeIndex::root()`
+        self.get_children(hash)?
+            .ok_or(lookup_error(MerkleError::RootNotInStore(hash)))?;
+
+        for i in (0..index.depth()).rev() {
+            let (left, right) = self
+                .get_children(hash)?
+                .ok_or(lookup_error(MerkleError::NodeNotInStore(hash, index)))?;
+
+            let bit = (index.value() >> i) & 1;
+            hash = if bit == 0 {
+                path.push(right);
+                left
+            } else {
+                path.push(left);
+                right
+            }
+        }
+
+        // the path is computed from root to leaf, so it must be reversed
+        path.reverse();
+
+        Ok((hash, MerklePath::new(path)))
+    }
+}
+
+impl AdviceProvider for DiskAdviceProvider {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack<P: ProcessState>(&mut self, process: &P) -> Result<Felt, ExecutionError> {
+        self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))
+    }
+
+    fn pop_stack_word<P: ProcessState>(&mut self, process: &P) -> Result<Word, ExecutionError> {
+        if self.stack.len() < 4 {
+            return Err(ExecutionError::AdviceStackReadFailed(process.clk()));
+        }
+
+        let idx = self.stack.len() - 4;
+        let result =
+            [self.stack[idx + 3], self.stack[idx + 2], self.stack[idx + 1], self.stack[idx]];
+
+        self.stack.truncate(idx);
+
+        Ok(result)
+    }
+
+    fn pop_stack_dword<P: ProcessState>(
+        &mut self,
+        process: &P,
+    ) -> Result<[Word; 2], ExecutionError> {
+        let word0 = self.pop_stack_word(process)?;
+        let word1 = self.pop_stack_word(process)?;
+
+        Ok([word0, word1])
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match source {
+            AdviceSource::Value(value) => {
+                self.stack.push(value);
+            }
+            AdviceSource::Word(word) => {
+                self.stack.extend(word.iter().rev());
+            }
+            AdviceSource::Map { key, include_len } => {
+                let values = self
+                    .map
+                    .get(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceMapKeyNotFound(key))?;
+
+                self.stack.extend(values.iter().rev());
+                if include_len {
+                    self.stack.push(Felt::from(values.len() as u64));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_signature(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError> {
+        let pk_sk = self
+            .map
+            .get(&pub_key.into_bytes())
+            .ok_or(ExecutionError::AdviceMapKeyNotFound(pub_key))?;
+
+        match kind {
+            SignatureKind::RpoFalcon512 => injectors::dsa::falcon_sign(pk_sk, msg),
+            SignatureKind::SchnorrEcgfp5 => injectors::dsa::schnorr_ecgfp5_sign(pk_sk, msg),
+        }
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    fn get_mapped_values(&self, key: &[u8; 32]) -> Option<&[Felt]> {
+        self.map.get(key).map(|v| v.as_slice())
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        let key = key.into_bytes();
+        self.storage.insert_map_entry(key, &values).map_err(storage_error)?;
+        self.map.insert(key, values);
+        Ok(())
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.get_value_path(root.into(), index).map(|(value, _)| value.into())
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.get_value_path(root.into(), index).map(|(_, path)| path)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        let tree_depth = u8::try_from(tree_depth.as_int())
+            .map_err(|_| ExecutionError::InvalidTreeDepth { depth: *tree_depth })?;
+        let index = index.as_int();
+
+        // validate depth and index
+        if tree_depth > 64 {
+            return Err(lookup_error(MerkleError::DepthTooBig(tree_depth as u64)));
+        }
+        NodeIndex::new(tree_depth, index).map_err(lookup_error)?;
+
+        // check if the root exists, providing the proper error report if it doesn't
+        let empty = EmptySubtreeRoots::empty_hashes(tree_depth);
+        let mut hash = root.into();
+        if self.get_children(hash)?.is_none() {
+            return Err(lookup_error(MerkleError::RootNotInStore(hash)));
+        }
+
+        // we traverse from root to leaf, so the path is reversed
+        let mut path = (index << (64 - tree_depth)).reverse_bits();
+
+        // iterate every depth and reconstruct the path from root to leaf
+        for depth in 0..=tree_depth {
+            // we short-circuit if an empty node has been found
+            if hash == empty[depth as usize] {
+                return Ok(depth);
+            }
+
+            // fetch the children pair, mapped by its parent hash
+            let (left, right) = match self.get_children(hash)? {
+                Some(children) => children,
+                None => return Ok(depth),
+            };
+
+            // traverse down
+            hash = if path & 1 == 0 { left } else { right };
+            path >>= 1;
+        }
+
+        // return an error because we exhausted the index but didn't find either a leaf or an
+        // empty node
+        Err(lookup_error(MerkleError::DepthTooBig(tree_depth as u64 + 1)))
+    }
+
+    fn find_lone_leaf(
+        &self,
+        root: Word,
+        root_index: NodeIndex,
+        tree_depth: u8,
+    ) -> Result<Option<(NodeIndex, Word)>, ExecutionError> {
+        // we set max depth at u64::BITS as this is the largest meaningful value for a 64-bit index
+        const MAX_DEPTH: u8 = u64::BITS as u8;
+        if tree_depth > MAX_DEPTH {
+            return Err(lookup_error(MerkleError::DepthTooBig(tree_depth as u64)));
+        }
+        let empty = EmptySubtreeRoots::empty_hashes(MAX_DEPTH);
+
+        let mut node: RpoDigest = root.into();
+        if self.get_children(node)?.is_none() {
+            return Err(lookup_error(MerkleError::RootNotInStore(node)));
+        }
+
+        let mut index = root_index;
+        if index.depth() > tree_depth {
+            return Err(lookup_error(MerkleError::DepthTooBig(index.depth() as u64)));
+        }
+
+        // traverse down following the path of single non-empty nodes; this works because if a
+        // node has two empty children it cannot contain a lone leaf. similarly if a node has
+        // two non-empty children it must contain at least two leaves.
+        for depth in index.depth()..tree_depth {
+            // if the node is a leaf, return; otherwise, examine the node's children
+            let (left, right) = match self.get_children(node)? {
+                Some(children) => children,
+                None => return Ok(Some((index, node.into()))),
+            };
+
+            let empty_node = empty[depth as usize + 1];
+            node = if left != empty_node && right == empty_node {
+                index = index.left_child();
+                left
+            } else if left == empty_node && right != empty_node {
+                index = index.right_child();
+                right
+            } else {
+                return Ok(None);
+            };
+        }
+
+        // if we are here, we got to `tree_depth`; thus, either the current node is a leaf node,
+        // and so we return it, or it is an internal node, and then we return an error
+        if self.get_children(node)?.is_some() {
+            Err(lookup_error(MerkleError::DepthTooBig(tree_depth as u64 + 1)))
+        } else {
+            Ok(Some((index, node.into())))
+        }
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        let node_index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        let (old_value, path) = self.get_value_path(root.into(), node_index)?;
+
+        // performs the update only if the node value differs from the opening
+        let new_value: RpoDigest = value.into();
+        if new_value == old_value {
+            return Ok((path, root));
+        }
+
+        let inner_nodes = path
+            .inner_nodes(node_index.value(), new_value)
+            .map_err(ExecutionError::MerkleStoreUpdateFailed)?;
+
+        let mut new_root = root;
+        for InnerNodeInfo { value, left, right } in inner_nodes {
+            self.insert_node(value, left, right)?;
+            new_root = value.into();
+        }
+
+        Ok((path, new_root))
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        let (left, right) = (lhs.into(), rhs.into());
+        let parent = Rpo256::merge(&[left, right]);
+        self.insert_node(parent, left, right)?;
+
+        Ok(parent.into())
+    }
+
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
+    where
+        I: Iterator<Item = R>,
+        R: Borrow<RpoDigest>,
+    {
+        let mut nodes = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut to_visit: Vec<RpoDigest> = roots.map(|root| *root.borrow()).collect();
+
+        while let Some(node) = to_visit.pop() {
+            // nodes of empty subtrees are always contained in a Merkle store
+            if self.empty_nodes.contains_key(&node) || !visited.insert(node) {
+                continue;
+            }
+
+            if let Some((left, right)) = self.get_children(node)? {
+                nodes.push(InnerNodeInfo {
+                    value: node,
+                    left,
+                    right,
+                });
+                to_visit.push(left);
+                to_visit.push(right);
+            }
+        }
+
+        Ok(nodes.into_iter().collect())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn lookup_error(err: MerkleError) -> ExecutionError {
+    ExecutionError::MerkleStoreLookupFailed(err)
+}
+
+fn storage_error(err: io::Error) -> ExecutionError {
+    ExecutionError::AdviceStorageFailed(err.to_string())
+}
@@ -0,0 +1,335 @@
+use super::{BTreeMap, Felt, RpoDigest, StarkField, Vec};
+use std::{
+    cell::RefCell,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes identifying an advice storage file.
+const MAGIC: &[u8; 8] = b"MIDENADV";
+
+/// Version of the storage file format.
+const VERSION: u32 = 1;
+
+/// Length of the file header (magic bytes followed by the format version).
+const HEADER_LEN: u64 = 12;
+
+/// Tag of a record holding an internal node of a Merkle tree.
+const NODE_TAG: u8 = 0;
+
+/// Tag of a record holding an advice map entry.
+const MAP_TAG: u8 = 1;
+
+/// Length of a Merkle node record: tag, node digest, left child digest, right child digest.
+const NODE_RECORD_LEN: usize = 1 + 3 * 32;
+
+/// Number of buffered bytes after which pending records are written to the file.
+const MAX_PENDING_BYTES: usize = 1 << 20;
+
+// ADVICE STORAGE
+// ================================================================================================
+
+/// An append-only key-value file holding internal Merkle nodes and advice map entries.
+///
+/// The file consists of a header followed by a sequence of records:
+/// - Merkle node records: `[0u8, node, left, right]`, where each digest is 32 bytes long.
+/// - Advice map records: `[1u8, key, len, values]`, where the key is 32 bytes long, `len` is a
+///   little-endian `u32` and `values` are `len` little-endian `u64` field elements.
+///
+/// Merkle nodes are content-addressed, and thus, are never rewritten. An advice map record
+/// overrides all previous records with the same key.
+///
+/// Only the file offsets of Merkle nodes are kept in memory, indexed by the first 8 bytes of the
+/// node digest (with a fallback for the rare case of colliding prefixes); the nodes themselves
+/// are read from the file on demand. Newly inserted records are buffered and appended to the file
+/// in batches.
+pub struct AdviceStorage {
+    file: RefCell<File>,
+    file_len: u64,
+    index: BTreeMap<u64, u64>,
+    collisions: BTreeMap<RpoDigest, u64>,
+    pending: Vec<u8>,
+}
+
+impl AdviceStorage {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens the storage file at the specified path, creating it if it does not exist yet.
+    ///
+    /// Returns the storage together with the advice map persisted in the file. If the last record
+    /// in the file is incomplete (e.g., because the process was interrupted while appending it),
+    /// the record is discarded and the file is truncated to the end of the previous record.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened, or if its contents are malformed.
+    #[allow(clippy::type_complexity)]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<(Self, BTreeMap<[u8; 32], Vec<Felt>>)> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let file_len = file.metadata()?.len();
+
+        // the storage gets its own handle so that reading nodes while replaying the records does
+        // not move the cursor of the reader below
+        let mut storage = Self {
+            file: RefCell::new(OpenOptions::new().read(true).write(true).open(path)?),
+            file_len,
+            index: BTreeMap::new(),
+            collisions: BTreeMap::new(),
+            pending: Vec::new(),
+        };
+        let mut map = BTreeMap::new();
+
+        if file_len == 0 {
+            file.write_all(MAGIC)?;
+            file.write_all(&VERSION.to_le_bytes())?;
+            storage.file_len = HEADER_LEN;
+            return Ok((storage, map));
+        }
+
+        let mut reader = BufReader::new(file);
+        let mut header = [0u8; HEADER_LEN as usize];
+        reader.read_exact(&mut header)?;
+        if &header[..8] != MAGIC {
+            return Err(invalid_data("not an advice storage file"));
+        }
+        if header[8..] != VERSION.to_le_bytes() {
+            return Err(invalid_data("unsupported advice storage file version"));
+        }
+
+        // replay all records to rebuild the node index and the advice map; if appending records
+        // was interrupted, the last record may be incomplete, in which case it is discarded and
+        // the file is truncated to the end of the last complete record
+        let mut offset = HEADER_LEN;
+        while offset < file_len {
+            match storage.replay_record(&mut reader, offset, &mut map) {
+                Ok(record_len) => offset += record_len,
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                    storage.file.get_mut().set_len(offset)?;
+                    storage.file_len = offset;
+                    break;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok((storage, map))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the children of the specified internal Merkle node, or None if the node is not in
+    /// the storage.
+    pub fn get_node(&self, node: &RpoDigest) -> io::Result<Option<(RpoDigest, RpoDigest)>> {
+        let offset = match self.collisions.get(node) {
+            Some(&offset) => offset,
+            None => match self.index.get(&short_key(node)) {
+                Some(&offset) => offset,
+                None => return Ok(None),
+            },
+        };
+
+        let (stored, left, right) = self.read_node(offset)?;
+        Ok((stored == *node).then_some((left, right)))
+    }
+
+    /// Returns true if the specified internal Merkle node is in the storage.
+    pub fn contains_node(&self, node: &RpoDigest) -> io::Result<bool> {
+        self.get_node(node).map(|children| children.is_some())
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds an internal Merkle node with the specified children to the storage.
+    ///
+    /// This is a noop if the node is already in the storage.
+    pub fn insert_node(
+        &mut self,
+        node: RpoDigest,
+        left: RpoDigest,
+        right: RpoDigest,
+    ) -> io::Result<()> {
+        if self.contains_node(&node)? {
+            return Ok(());
+        }
+
+        let offset = self.file_len + self.pending.len() as u64;
+        self.pending.push(NODE_TAG);
+        self.pending.extend_from_slice(&node.as_bytes());
+        self.pending.extend_from_slice(&left.as_bytes());
+        self.pending.extend_from_slice(&right.as_bytes());
+        self.insert_index(node, offset)?;
+
+        self.flush_if_full()
+    }
+
+    /// Persists an advice map entry. Any previously persisted values under the same key are
+    /// overridden.
+    pub fn insert_map_entry(&mut self, key: [u8; 32], values: &[Felt]) -> io::Result<()> {
+        let len = u32::try_from(values.len())
+            .map_err(|_| invalid_data("advice map value is too long"))?;
+
+        self.pending.push(MAP_TAG);
+        self.pending.extend_from_slice(&key);
+        self.pending.extend_from_slice(&len.to_le_bytes());
+        for value in values {
+            self.pending.extend_from_slice(&value.as_int().to_le_bytes());
+        }
+
+        self.flush_if_full()
+    }
+
+    /// Writes all buffered records to the storage file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut file = self.file.borrow_mut();
+        file.seek(SeekFrom::Start(self.file_len))?;
+        file.write_all(&self.pending)?;
+        file.flush()?;
+
+        self.file_len += self.pending.len() as u64;
+        self.pending.clear();
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the record located at the specified offset from the reader, adding Merkle nodes to
+    /// the index and advice map entries to the specified map, and returns the length of the
+    /// record.
+    ///
+    /// Nothing is added if the record is incomplete, in which case an error of kind
+    /// [io::ErrorKind::UnexpectedEof] is returned.
+    fn replay_record<R: Read>(
+        &mut self,
+        reader: &mut R,
+        offset: u64,
+        map: &mut BTreeMap<[u8; 32], Vec<Felt>>,
+    ) -> io::Result<u64> {
+        let mut tag = [0u8; 1];
+        reader.read_exact(&mut tag)?;
+        match tag[0] {
+            NODE_TAG => {
+                let mut record = [0u8; NODE_RECORD_LEN - 1];
+                reader.read_exact(&mut record)?;
+                let node = read_digest(&record[..32])?;
+                self.insert_index(node, offset)?;
+                Ok(NODE_RECORD_LEN as u64)
+            }
+            MAP_TAG => {
+                let mut key = [0u8; 32];
+                reader.read_exact(&mut key)?;
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                let len = u32::from_le_bytes(len) as usize;
+                let mut values = Vec::new();
+                for _ in 0..len {
+                    let mut value = [0u8; 8];
+                    reader.read_exact(&mut value)?;
+                    values.push(read_felt(value)?);
+                }
+                map.insert(key, values);
+                Ok((1 + 32 + 4 + 8 * len) as u64)
+            }
+            _ => Err(invalid_data("invalid advice storage record tag")),
+        }
+    }
+
+    /// Adds the offset of a Merkle node record to the index.
+    fn insert_index(&mut self, node: RpoDigest, offset: u64) -> io::Result<()> {
+        let key = short_key(&node);
+        match self.index.get(&key) {
+            None => {
+                self.index.insert(key, offset);
+            }
+            Some(&existing) => {
+                let (stored, ..) = self.read_node(existing)?;
+                if stored != node {
+                    self.collisions.insert(node, offset);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the Merkle node record located at the specified offset.
+    fn read_node(&self, offset: u64) -> io::Result<(RpoDigest, RpoDigest, RpoDigest)> {
+        let mut record = [0u8; NODE_RECORD_LEN];
+        if offset >= self.file_len {
+            // the record has not been written to the file yet
+            let start = (offset - self.file_len) as usize;
+            record.copy_from_slice(&self.pending[start..start + NODE_RECORD_LEN]);
+        } else {
+            let mut file = self.file.borrow_mut();
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_exact(&mut record)?;
+        }
+
+        if record[0] != NODE_TAG {
+            return Err(invalid_data("expected a Merkle node record"));
+        }
+
+        Ok((
+            read_digest(&record[1..33])?,
+            read_digest(&record[33..65])?,
+            read_digest(&record[65..97])?,
+        ))
+    }
+
+    /// Writes the buffered records to the file if the buffer has grown too large.
+    fn flush_if_full(&mut self) -> io::Result<()> {
+        if self.pending.len() >= MAX_PENDING_BYTES {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AdviceStorage {
+    fn drop(&mut self) {
+        // errors cannot be reported from here; callers who need to handle them should flush the
+        // storage explicitly
+        let _ = self.flush();
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the key under which the specified node is stored in the index.
+fn short_key(node: &RpoDigest) -> u64 {
+    let bytes = node.as_bytes();
+    u64::from_le_bytes(bytes[..8].try_into().expect("slice of 8 bytes"))
+}
+
+fn read_digest(bytes: &[u8]) -> io::Result<RpoDigest> {
+    let bytes: [u8; 32] = bytes.try_into().expect("slice of 32 bytes");
+    RpoDigest::try_from(bytes).map_err(|_| invalid_data("invalid digest"))
+}
+
+fn read_felt(bytes: [u8; 8]) -> io::Result<Felt> {
+    let value = u64::from_le_bytes(bytes);
+    if value >= Felt::MODULUS {
+        return Err(invalid_data("invalid field element"));
+    }
+    Ok(Felt::new(value))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
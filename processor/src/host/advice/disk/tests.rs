@@ -0,0 +1,228 @@
+use super::{
+    AdviceInputs, AdviceProvider, DiskAdviceProvider, ExecutionError, Felt, IntoBytes, MerkleStore,
+    NodeIndex, RpoDigest, StarkField, Vec, Word,
+};
+use crate::{DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs};
+use miden_assembly::Assembler;
+use std::{fs, path::PathBuf};
+use vm_core::{
+    crypto::merkle::{MerkleError, MerkleTree},
+    ONE, ZERO,
+};
+
+// MERKLE STORE
+// ================================================================================================
+
+#[test]
+fn merkle_store_nodes_are_loaded_from_disk() {
+    let path = TempFile::new("load");
+    let tree = build_tree(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    // write the tree into the storage and re-open it without any inputs
+    drop(DiskAdviceProvider::open(&path.0, tree_inputs(&tree)).unwrap());
+    let provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+
+    let root: Word = tree.root().into();
+    let depth = Felt::from(tree.depth());
+    for index in 0..8_u64 {
+        let node_index = NodeIndex::new(tree.depth(), index).unwrap();
+        let node = provider.get_tree_node(root, &depth, &Felt::new(index)).unwrap();
+        assert_eq!(node, Word::from(tree.get_node(node_index).unwrap()));
+
+        let path = provider.get_merkle_path(root, &depth, &Felt::new(index)).unwrap();
+        assert_eq!(path, tree.get_path(node_index).unwrap());
+    }
+
+    let err = provider.get_tree_node([ONE; 4], &depth, &ZERO).unwrap_err();
+    assert!(matches!(
+        err,
+        ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(_))
+    ));
+}
+
+#[test]
+fn merkle_store_updates_are_persisted() {
+    let path = TempFile::new("update");
+    let tree = build_tree(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let mut provider = DiskAdviceProvider::open(&path.0, tree_inputs(&tree)).unwrap();
+    let root: Word = tree.root().into();
+    let depth = Felt::from(tree.depth());
+    let new_value = [Felt::new(42); 4];
+    let (path_to_node, new_root) =
+        provider.update_merkle_node(root, &depth, &Felt::new(5), new_value).unwrap();
+    drop(provider);
+
+    // compute the expected root of the updated tree
+    let mut expected_tree = tree.clone();
+    expected_tree.update_leaf(5, new_value).unwrap();
+    assert_eq!(new_root, Word::from(expected_tree.root()));
+    assert_eq!(path_to_node, tree.get_path(NodeIndex::new(3, 5).unwrap()).unwrap());
+
+    // both the original and the updated trees must be available after re-opening the storage
+    let provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+    assert_eq!(provider.get_tree_node(new_root, &depth, &Felt::new(5)).unwrap(), new_value);
+    assert_eq!(
+        provider.get_tree_node(root, &depth, &Felt::new(5)).unwrap(),
+        Word::from(tree.get_node(NodeIndex::new(3, 5).unwrap()).unwrap())
+    );
+    assert!(provider.has_merkle_root(expected_tree.root()).unwrap());
+}
+
+#[test]
+fn merkle_store_merge_and_subset() {
+    let path = TempFile::new("merge");
+    let left = build_tree(&[1, 2, 3, 4]);
+    let right = build_tree(&[5, 6, 7, 8]);
+
+    let mut store = MerkleStore::default();
+    store.extend(left.inner_nodes());
+    store.extend(right.inner_nodes());
+    let inputs = AdviceInputs::default().with_merkle_store(store);
+
+    let mut provider = DiskAdviceProvider::open(&path.0, inputs).unwrap();
+    let root = provider.merge_roots(left.root().into(), right.root().into()).unwrap();
+
+    let expected = build_tree(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(root, Word::from(expected.root()));
+
+    let subset = provider.get_store_subset([expected.root()].iter()).unwrap();
+    for index in 0..8_u64 {
+        let node_index = NodeIndex::new(3, index).unwrap();
+        assert_eq!(
+            subset.get_node(expected.root(), node_index).unwrap(),
+            expected.get_node(node_index).unwrap()
+        );
+    }
+}
+
+// ADVICE MAP
+// ================================================================================================
+
+#[test]
+fn advice_map_is_persisted() {
+    let path = TempFile::new("map");
+    let key_a = [Felt::new(1); 4];
+    let key_b = [Felt::new(2); 4];
+    let inputs = AdviceInputs::default().with_map([(key_a.into_bytes(), vec![Felt::new(3)])]);
+
+    let mut provider = DiskAdviceProvider::open(&path.0, inputs).unwrap();
+    provider.insert_into_map(key_b, vec![Felt::new(4), Felt::new(5)]).unwrap();
+    provider.insert_into_map(key_a, vec![Felt::new(6)]).unwrap();
+    drop(provider);
+
+    let provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+    assert_eq!(provider.get_mapped_values(&key_a.into_bytes()), Some(&[Felt::new(6)][..]));
+    assert_eq!(
+        provider.get_mapped_values(&key_b.into_bytes()),
+        Some(&[Felt::new(4), Felt::new(5)][..])
+    );
+}
+
+#[test]
+fn invalid_storage_file_is_rejected() {
+    let path = TempFile::new("invalid");
+    fs::write(&path.0, b"not an advice storage file").unwrap();
+
+    assert!(DiskAdviceProvider::open(&path.0, AdviceInputs::default()).is_err());
+}
+
+#[test]
+fn incomplete_trailing_record_is_discarded() {
+    let path = TempFile::new("truncated");
+    let tree = build_tree(&[1, 2, 3, 4]);
+    let key = [Felt::new(1); 4];
+    let inputs = tree_inputs(&tree).with_map([(key.into_bytes(), vec![Felt::new(2)])]);
+    drop(DiskAdviceProvider::open(&path.0, inputs).unwrap());
+    let complete_len = fs::metadata(&path.0).unwrap().len();
+
+    // simulate interrupted appends of a Merkle node record and of an advice map record
+    for partial_record in [vec![0u8; 40], [&[1u8][..], &[7u8; 32], &3u32.to_le_bytes()].concat()] {
+        let mut bytes = fs::read(&path.0).unwrap();
+        bytes.extend_from_slice(&partial_record);
+        fs::write(&path.0, bytes).unwrap();
+
+        let mut provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+        assert_eq!(fs::metadata(&path.0).unwrap().len(), complete_len);
+        assert!(provider.has_merkle_root(tree.root()).unwrap());
+        assert_eq!(provider.get_mapped_values(&key.into_bytes()), Some(&[Felt::new(2)][..]));
+
+        // records appended after the truncation are read back correctly
+        let key_b = [Felt::new(3); 4];
+        provider.insert_into_map(key_b, vec![Felt::new(4)]).unwrap();
+        drop(provider);
+
+        let provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+        assert_eq!(provider.get_mapped_values(&key_b.into_bytes()), Some(&[Felt::new(4)][..]));
+        drop(provider);
+
+        // restore the file to its state before the interrupted append
+        let mut bytes = fs::read(&path.0).unwrap();
+        bytes.truncate(complete_len as usize);
+        fs::write(&path.0, bytes).unwrap();
+    }
+}
+
+// PROGRAM EXECUTION
+// ================================================================================================
+
+#[test]
+fn execute_mtree_set_with_disk_provider() {
+    let path = TempFile::new("execute");
+    let tree = build_tree(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let program = Assembler::default().compile("begin mtree_set end").unwrap();
+    let mut stack_inputs = vec![9, 9, 9, 9];
+    stack_inputs.extend(tree.root().iter().map(|v| v.as_int()));
+    stack_inputs.extend([2, tree.depth() as u64]);
+    let stack_inputs = StackInputs::try_from_values(stack_inputs).unwrap();
+
+    // execute the program against the in-memory and the disk-backed providers
+    let mem_host = DefaultHost::new(MemAdviceProvider::from(tree_inputs(&tree)));
+    let expected = crate::execute(&program, stack_inputs.clone(), mem_host, Default::default())
+        .unwrap()
+        .stack_outputs()
+        .clone();
+
+    let disk_host =
+        DefaultHost::new(DiskAdviceProvider::open(&path.0, tree_inputs(&tree)).unwrap());
+    let trace =
+        crate::execute(&program, stack_inputs, disk_host, ExecutionOptions::default()).unwrap();
+    assert_eq!(trace.stack_outputs(), &expected);
+
+    // the updated tree must be available in the storage
+    let new_root: Vec<Felt> = expected.stack()[4..8].iter().rev().map(|&v| Felt::new(v)).collect();
+    let new_root = RpoDigest::new(new_root.try_into().unwrap());
+    let provider = DiskAdviceProvider::open(&path.0, AdviceInputs::default()).unwrap();
+    assert!(provider.has_merkle_root(new_root).unwrap());
+}
+
+// HELPERS
+// ================================================================================================
+
+fn build_tree(values: &[u64]) -> MerkleTree {
+    let leaves = values.iter().map(|&v| [Felt::new(v), ZERO, ZERO, ZERO]).collect::<Vec<_>>();
+    MerkleTree::new(leaves).unwrap()
+}
+
+fn tree_inputs(tree: &MerkleTree) -> AdviceInputs {
+    AdviceInputs::default().with_merkle_store(tree.into())
+}
+
+/// A path to a temporary storage file which is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let file_name = format!("miden-disk-advice-{}-{name}.bin", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let _ = fs::remove_file(&path);
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
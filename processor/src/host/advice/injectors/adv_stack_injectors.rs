@@ -1,6 +1,9 @@
-use super::super::{AdviceSource, ExecutionError, Felt, HostResponse, StarkField};
+use super::{
+    super::{AdviceSource, ExecutionError, Felt, HostResponse, StarkField},
+    adv_map_injectors::get_mem_addr_range,
+};
 use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, Vec};
-use vm_core::{QuadExtension, SignatureKind};
+use vm_core::{QuadExtension, SignatureKind, Word, EMPTY_WORD};
 use winter_prover::math::fft;
 
 // TYPE ALIASES
@@ -149,6 +152,63 @@ pub(crate) fn push_u64_div_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the result of division of two unsigned integers represented using 32-bit limbs (both
+/// the quotient and the remainder) onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+///   Advice stack: [q0, ..., q_{m-1}, r0, ..., r_{n-1}, ...]
+///
+/// Where m = `dividend_limbs` and n = `divisor_limbs`, and a0, b0, q0 and r0 are the least
+/// significant limbs of the dividend, the divisor, the quotient and the remainder respectively.
+///
+/// # Errors
+/// Returns an error if:
+/// - The divisor is ZERO.
+/// - Any of the limbs is not a valid u32 value.
+pub(crate) fn push_un_div_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    dividend_limbs: usize,
+    divisor_limbs: usize,
+) -> Result<HostResponse, ExecutionError> {
+    let stack = process.get_stack_state();
+
+    // limbs are located on the stack with the most significant limb closest to the top; they are
+    // read into vectors with the least significant limb first
+    let read_limbs = |offset: usize, num_limbs: usize| {
+        (offset..offset + num_limbs)
+            .rev()
+            .map(|i| {
+                let value = stack.get(i).copied().unwrap_or(Felt::ZERO);
+                u32::try_from(value.as_int())
+                    .map_err(|_| ExecutionError::NotU32Value(value, Felt::ZERO))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    let divisor = read_limbs(0, divisor_limbs)?;
+    if divisor.iter().all(|&limb| limb == 0) {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+    let dividend = read_limbs(divisor_limbs, dividend_limbs)?;
+
+    let (quotient, remainder) = div_rem_limbs(&dividend, &divisor);
+
+    for &limb in remainder.iter().rev() {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+    for &limb in quotient.iter().rev() {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
 /// computes its multiplicative inverse and push the result onto the advice stack.
 ///
@@ -184,6 +244,64 @@ pub(crate) fn push_ext2_inv_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes onto the advice stack the address of the first entry in a sorted array of words
+/// which is not less than the specified key.
+///
+/// Inputs:
+///   Operand stack: [KEY, start_addr, end_addr, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [KEY, start_addr, end_addr, ...]
+///   Advice stack: [addr, ...]
+///
+/// Where the array consists of entries of `stride` words located in memory[start_addr..end_addr],
+/// and the first word of each entry is used as the sort key. Words are compared
+/// lexicographically, with the element which is on top of the stack when the word is loaded being
+/// the most significant one. If all entries are less than `KEY`, `end_addr` is pushed onto the
+/// advice stack.
+///
+/// The array is assumed to be sorted; the returned address is only meaningful if it is.
+///
+/// # Errors
+/// Returns an error if:
+/// - `start_addr` or `end_addr` is greater than or equal to 2^32.
+/// - `start_addr` > `end_addr`, or `end_addr - start_addr` is not a multiple of `stride`.
+pub(crate) fn push_mem_lower_bound<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    stride: usize,
+) -> Result<HostResponse, ExecutionError> {
+    let (start_addr, end_addr) = get_mem_addr_range(process, 4, 5)?;
+    if stride == 0 || (end_addr - start_addr) as usize % stride != 0 {
+        return Err(ExecutionError::InvalidMemoryRange {
+            start_addr: start_addr as u64,
+            end_addr: end_addr as u64,
+        });
+    }
+
+    let ctx = process.ctx();
+    let key = word_sort_key(process.get_stack_word(0));
+
+    // binary search for the first entry which is not less than the key
+    let (mut lo, mut hi) = (0, (end_addr - start_addr) as usize / stride);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let addr = start_addr + (mid * stride) as u32;
+        let word = process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
+        if word_sort_key(word) < key {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    let addr = start_addr as u64 + (lo * stride) as u64;
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(addr)))?;
+
+    Ok(HostResponse::None)
+}
+
 /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
 ///  into a polynomial in coefficient form and pushes the result into the advice stack.
 ///
@@ -299,6 +417,59 @@ pub(crate) fn push_signature<S: ProcessState, A: AdviceProvider>(
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Divides two unsigned integers represented using 32-bit limbs (least significant limb first)
+/// using binary long division.
+///
+/// The quotient is returned using as many limbs as the dividend, and the remainder using as many
+/// limbs as the divisor. The divisor is assumed to be non-zero.
+fn div_rem_limbs(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0_u32; dividend.len()];
+    // the remainder is one limb wider than the divisor so that doubling it never overflows
+    let mut remainder = vec![0_u32; divisor.len() + 1];
+
+    for i in (0..dividend.len() * 32).rev() {
+        let mut carry = (dividend[i / 32] >> (i % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        if !lt_limbs(&remainder, divisor) {
+            let mut borrow = 0;
+            for (j, limb) in remainder.iter_mut().enumerate() {
+                let (diff, b1) = limb.overflowing_sub(divisor.get(j).copied().unwrap_or(0));
+                let (diff, b2) = diff.overflowing_sub(borrow);
+                *limb = diff;
+                borrow = (b1 | b2) as u32;
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+
+    remainder.truncate(divisor.len());
+    (quotient, remainder)
+}
+
+/// Returns true if the integer represented by limbs `a` is smaller than the one represented by
+/// limbs `b`; both are expected to have the least significant limb first.
+fn lt_limbs(a: &[u32], b: &[u32]) -> bool {
+    for i in (0..a.len().max(b.len())).rev() {
+        let a_limb = a.get(i).copied().unwrap_or(0);
+        let b_limb = b.get(i).copied().unwrap_or(0);
+        if a_limb != b_limb {
+            return a_limb < b_limb;
+        }
+    }
+    false
+}
+
+/// Returns the elements of the word as integers, ordered from the most significant one (i.e.,
+/// the last element of the word) to the least significant one.
+fn word_sort_key(word: Word) -> [u64; 4] {
+    [word[3].as_int(), word[2].as_int(), word[1].as_int(), word[0].as_int()]
+}
+
 fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let hi = Felt::new(value >> 32);
     let lo = Felt::new((value as u32) as u64);
@@ -4,6 +4,14 @@ use vm_core::{
     utils::Deserializable,
 };
 
+#[cfg(feature = "std")]
+use super::ecgfp5::{self, Point, SCALAR_LIMBS};
+#[cfg(feature = "std")]
+use vm_core::{
+    crypto::hash::{Rpo256, RpoDigest},
+    ONE, ZERO,
+};
+
 /// Gets as input a vector containing an expanded public key and its associated secret key, and a
 /// word representing a message and outputs a vector of values to be pushed onto the advice stack.
 /// The values are the ones required for a Falcon signature verification inside the VM and they are:
@@ -71,3 +79,112 @@ pub fn falcon_sign(pk_sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionErro
         "RPO Falcon512 signature generation is not available in no_std context",
     ))
 }
+
+/// Gets as input a vector containing a secret key for the Schnorr signature scheme over the
+/// ecgfp5 group and a word representing a message, and outputs a vector of values to be pushed
+/// onto the advice stack. The values are the ones required for a signature verification inside
+/// the VM and they are:
+///
+/// 1. The public key point represented by its 10 coordinates [x0, ..., x4, y0, ..., y4].
+/// 2. The encoding of the commitment point R represented as 5 field elements.
+/// 3. The response scalar s represented as 10 u32 limbs.
+///
+/// Each of the above is laid out so that a single `adv_push.n` leaves it on the operand stack in
+/// its natural order, i.e. with the first coordinate or the least significant limb on top.
+///
+/// The secret key is expected to be a non-zero scalar smaller than the group order, represented
+/// as 10 u32 limbs with the least significant limb first. The nonce is derived deterministically
+/// from the secret key and the message, and the challenge is computed as
+/// `e = hash(hash(R), hash(PK, MSG))`, where `PK = hash(pk)` is the commitment to the public key
+/// point (see `hash_elements_reversed` for how points are hashed).
+///
+/// # Errors
+/// Will return an error if either:
+/// - The secret key is malformed due to either incorrect length or an out of range value.
+/// - The signature generation failed.
+#[cfg(feature = "std")]
+pub fn schnorr_ecgfp5_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError> {
+    // Parse the secret key
+    let sk: [u32; SCALAR_LIMBS] = sk
+        .iter()
+        .map(|element| u32::try_from(element.as_int()))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+        .and_then(|limbs| limbs.try_into().ok())
+        .filter(|limbs| ecgfp5::is_canonical(limbs) && limbs.iter().any(|limb| *limb != 0))
+        .ok_or(ExecutionError::MalformedSignatureKey("Schnorr ecgfp5"))?;
+
+    let pk = Point::generator().mul(&sk).coordinates();
+    let pk_commitment = hash_elements_reversed(&pk);
+
+    // Derive the nonce from 512 bits of RPO output, so that its reduction modulo the group order
+    // is not biased
+    let mut seed: Vec<Felt> = sk.iter().map(|limb| Felt::from(*limb)).collect();
+    seed.extend_from_slice(&msg);
+    let mut wide_nonce = Vec::with_capacity(16);
+    for domain in [ZERO, ONE] {
+        seed.push(domain);
+        wide_nonce.extend(digest_to_limbs(Rpo256::hash_elements(&seed)));
+        seed.pop();
+    }
+    let k = ecgfp5::reduce(&wide_nonce);
+    if k.iter().all(|limb| *limb == 0) {
+        return Err(ExecutionError::FailedSignatureGeneration("Schnorr ecgfp5"));
+    }
+
+    let r = Point::generator().mul(&k).encode();
+
+    // e = hash(hash(R), hash(PK, MSG)), interpreted as a 256-bit integer
+    let challenge =
+        Rpo256::merge(&[hash_elements_reversed(&r), Rpo256::merge(&[pk_commitment, msg.into()])]);
+    let mut e = [0u32; SCALAR_LIMBS];
+    e[..8].copy_from_slice(&digest_to_limbs(challenge));
+
+    let s = ecgfp5::add_mod(&k, &ecgfp5::mul_mod(&e, &sk));
+
+    // adv_push.n pops the top of the advice stack first and places it deepest on the operand
+    // stack, so every component is written in reverse order
+    let mut result: Vec<Felt> = pk.iter().rev().copied().collect();
+    result.extend(r.iter().rev());
+    result.extend(s.iter().rev().map(|limb| Felt::from(*limb)));
+    result.reverse();
+    Ok(result)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn schnorr_ecgfp5_sign(_sk: &[Felt], _msg: Word) -> Result<Vec<Felt>, ExecutionError> {
+    Err(ExecutionError::FailedSignatureGeneration(
+        "Schnorr ecgfp5 signature generation is not available in no_std context",
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Hashes the provided elements the same way `std::crypto::dsa::schnorr_ecgfp5` hashes them off
+/// the operand stack: the elements are reversed, padded with zeros to a multiple of 4 and split
+/// into words, which are then sequentially merged, i.e. `hash(...hash(hash(w0, w1), w2)...)`.
+#[cfg(feature = "std")]
+fn hash_elements_reversed(elements: &[Felt]) -> RpoDigest {
+    let mut elements = elements.to_vec();
+    elements.reverse();
+    elements.resize(elements.len().next_multiple_of(4), ZERO);
+
+    let mut words = elements
+        .chunks(4)
+        .map(|chunk| RpoDigest::new([chunk[0], chunk[1], chunk[2], chunk[3]]));
+    let first = words.next().expect("at least two words");
+    words.fold(first, |acc, word| Rpo256::merge(&[acc, word]))
+}
+
+/// Splits each element of the digest into its low and high 32-bit halves, in that order.
+#[cfg(feature = "std")]
+fn digest_to_limbs(digest: RpoDigest) -> [u32; 8] {
+    let mut limbs = [0u32; 8];
+    for (i, element) in digest.as_elements().iter().enumerate() {
+        let value = element.as_int();
+        limbs[2 * i] = value as u32;
+        limbs[2 * i + 1] = (value >> 32) as u32;
+    }
+    limbs
+}
@@ -0,0 +1,374 @@
+use super::super::Felt;
+use core::ops::{Add, Mul, Sub};
+use vm_core::{FieldElement, ZERO};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of 32-bit limbs used to represent a scalar of the ecgfp5 group.
+pub const SCALAR_LIMBS: usize = 10;
+
+/// Order of the ecgfp5 group, i.e.
+/// N = 1067993516717146951041484916571792702745057740581727230159139685185762082554198619328292418486241
+/// in radix-2^32 form (least significant limb first).
+const N: [u32; SCALAR_LIMBS] = [
+    2492202977, 3893352854, 3609501852, 3901250617, 3484943929, 2147483622, 22, 2147483633,
+    2147483655, 2147483645,
+];
+
+/// Generator of the subgroup of order N, in short Weierstraß coordinates. Its encoding is w = 4.
+const GEN_X: [u64; 5] = [
+    11712523173042564207,
+    14090224426659529053,
+    13197813503519687414,
+    16280770174934269299,
+    15998333998318935536,
+];
+const GEN_Y: [u64; 5] = [
+    14639054205878357578,
+    17426078571020221072,
+    2548978194165003307,
+    8663895577921260088,
+    9793640284382595140,
+];
+
+// EXTENSION FIELD
+// ================================================================================================
+
+/// An element of GF(p^5) = GF(p)[x] / (x^5 - 3), with p = 2^64 - 2^32 + 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ext5([Felt; 5]);
+
+impl Ext5 {
+    pub const ZERO: Self = Self([ZERO; 5]);
+
+    pub fn from_int(value: u64) -> Self {
+        Self([Felt::new(value), ZERO, ZERO, ZERO, ZERO])
+    }
+
+    pub fn from_u64s(values: [u64; 5]) -> Self {
+        Self(values.map(Felt::new))
+    }
+
+    pub fn as_elements(&self) -> [Felt; 5] {
+        self.0
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Computes the multiplicative inverse of this element; zero is mapped to zero.
+    ///
+    /// See https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L709-L722
+    pub fn inv(self) -> Self {
+        let t0 = self.frobenius_once();
+        let t1 = t0 * t0.frobenius_once();
+        let t2 = t1 * t1.frobenius_twice();
+
+        let [a0, a1, a2, a3, a4] = self.0;
+        let [b0, b1, b2, b3, b4] = t2.0;
+        let t3 = a0 * b0 + Felt::new(3) * (a1 * b4 + a2 * b3 + a3 * b2 + a4 * b1);
+
+        let t4 = if t3 == ZERO { ZERO } else { t3.inv() };
+        Self(t2.0.map(|b| b * t4))
+    }
+
+    fn frobenius_once(self) -> Self {
+        let [a0, a1, a2, a3, a4] = self.0;
+        Self([
+            a0,
+            a1 * Felt::new(1041288259238279555),
+            a2 * Felt::new(15820824984080659046),
+            a3 * Felt::new(211587555138949697),
+            a4 * Felt::new(1373043270956696022),
+        ])
+    }
+
+    fn frobenius_twice(self) -> Self {
+        let [a0, a1, a2, a3, a4] = self.0;
+        Self([
+            a0,
+            a1 * Felt::new(15820824984080659046),
+            a2 * Felt::new(1373043270956696022),
+            a3 * Felt::new(1041288259238279555),
+            a4 * Felt::new(211587555138949697),
+        ])
+    }
+}
+
+impl Add for Ext5 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Sub for Ext5 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl Mul for Ext5 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let [a0, a1, a2, a3, a4] = self.0;
+        let [b0, b1, b2, b3, b4] = rhs.0;
+        let three = Felt::new(3);
+
+        Self([
+            a0 * b0 + three * (a1 * b4 + a2 * b3 + a3 * b2 + a4 * b1),
+            a0 * b1 + a1 * b0 + three * (a2 * b4 + a3 * b3 + a4 * b2),
+            a0 * b2 + a1 * b1 + a2 * b0 + three * (a3 * b4 + a4 * b3),
+            a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0 + three * (a4 * b4),
+            a0 * b4 + a1 * b3 + a2 * b2 + a3 * b1 + a4 * b0,
+        ])
+    }
+}
+
+// GROUP
+// ================================================================================================
+
+/// A point of the ecgfp5 group in short Weierstraß coordinates, mirroring the representation
+/// used by `std::math::ecgfp5::group`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    x: Ext5,
+    y: Ext5,
+    inf: bool,
+}
+
+impl Point {
+    pub fn neutral() -> Self {
+        Self {
+            x: Ext5::ZERO,
+            y: Ext5::ZERO,
+            inf: true,
+        }
+    }
+
+    pub fn generator() -> Self {
+        Self {
+            x: Ext5::from_u64s(GEN_X),
+            y: Ext5::from_u64s(GEN_Y),
+            inf: false,
+        }
+    }
+
+    /// Returns a/3 where a = 2 is the curve coefficient of the isomorphic double-odd curve.
+    fn adiv3() -> Ext5 {
+        Ext5::from_int(2) * Ext5::from_int(3).inv()
+    }
+
+    /// Returns the a' coefficient of the short Weierstraß curve.
+    fn a_prime() -> Ext5 {
+        let a = Ext5::from_int(2);
+        let b = Ext5([ZERO, Felt::new(263), ZERO, ZERO, ZERO]);
+        let three = Ext5::from_int(3);
+        (three * b - a.square()) * three.inv()
+    }
+
+    /// Returns the coordinates of this point as [x0, x1, x2, x3, x4, y0, y1, y2, y3, y4].
+    pub fn coordinates(&self) -> [Felt; 10] {
+        let mut coordinates = [ZERO; 10];
+        coordinates[..5].copy_from_slice(&self.x.as_elements());
+        coordinates[5..].copy_from_slice(&self.y.as_elements());
+        coordinates
+    }
+
+    /// Encodes this point as a single element of GF(p^5); the neutral element is encoded as zero.
+    ///
+    /// See https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1214-L1216
+    pub fn encode(&self) -> [Felt; 5] {
+        if self.inf {
+            Ext5::ZERO.as_elements()
+        } else {
+            (self.y * (Self::adiv3() - self.x).inv()).as_elements()
+        }
+    }
+
+    pub fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Adds two points.
+    ///
+    /// See https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1228-L1255
+    pub fn add(&self, rhs: &Self) -> Self {
+        if self.inf {
+            return *rhs;
+        }
+        if rhs.inf {
+            return *self;
+        }
+
+        let samex = self.x == rhs.x;
+        if samex && self.y != rhs.y {
+            return Self::neutral();
+        }
+
+        let lamb = if samex {
+            (Ext5::from_int(3) * self.x.square() + Self::a_prime())
+                * (Ext5::from_int(2) * self.y).inv()
+        } else {
+            (rhs.y - self.y) * (rhs.x - self.x).inv()
+        };
+
+        let x3 = lamb.square() - self.x - rhs.x;
+        let y3 = lamb * (self.x - x3) - self.y;
+        Self {
+            x: x3,
+            y: y3,
+            inf: false,
+        }
+    }
+
+    /// Multiplies this point by a scalar given in radix-2^32 form, using double-and-add.
+    pub fn mul(&self, scalar: &[u32; SCALAR_LIMBS]) -> Self {
+        let mut base = *self;
+        let mut res = Self::neutral();
+
+        for limb in scalar {
+            for i in 0..32 {
+                if (limb >> i) & 1 == 1 {
+                    res = res.add(&base);
+                }
+                base = base.double();
+            }
+        }
+
+        res
+    }
+}
+
+// SCALAR FIELD
+// ================================================================================================
+
+/// Returns true if the provided scalar is strictly less than the group order.
+pub fn is_canonical(a: &[u32; SCALAR_LIMBS]) -> bool {
+    for (a, n) in a.iter().zip(N.iter()).rev() {
+        if a != n {
+            return a < n;
+        }
+    }
+    false
+}
+
+/// Reduces an arbitrary width integer, given in radix-2^32 form (least significant limb first),
+/// modulo the group order.
+pub fn reduce(limbs: &[u32]) -> [u32; SCALAR_LIMBS] {
+    let mut res = [0u32; SCALAR_LIMBS];
+
+    for limb in limbs.iter().rev() {
+        for i in (0..32).rev() {
+            // res = 2 * res + bit; since res < N < 2^319, this never overflows 10 limbs
+            let mut carry = ((limb >> i) & 1) as u64;
+            for r in res.iter_mut() {
+                let v = ((*r as u64) << 1) | carry;
+                *r = v as u32;
+                carry = v >> 32;
+            }
+
+            if !is_canonical(&res) {
+                sub_order(&mut res);
+            }
+        }
+    }
+
+    res
+}
+
+/// Computes (a + b) mod N for canonical a and b.
+pub fn add_mod(a: &[u32; SCALAR_LIMBS], b: &[u32; SCALAR_LIMBS]) -> [u32; SCALAR_LIMBS] {
+    let mut res = [0u32; SCALAR_LIMBS];
+    let mut carry = 0u64;
+    for (r, (a, b)) in res.iter_mut().zip(a.iter().zip(b.iter())) {
+        let v = *a as u64 + *b as u64 + carry;
+        *r = v as u32;
+        carry = v >> 32;
+    }
+
+    // a + b < 2N < 2^320, so the sum always fits into 10 limbs
+    if !is_canonical(&res) {
+        sub_order(&mut res);
+    }
+    res
+}
+
+/// Computes (a * b) mod N.
+pub fn mul_mod(a: &[u32; SCALAR_LIMBS], b: &[u32; SCALAR_LIMBS]) -> [u32; SCALAR_LIMBS] {
+    let mut prod = [0u32; 2 * SCALAR_LIMBS];
+    for (i, a) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, b) in b.iter().enumerate() {
+            let v = prod[i + j] as u64 + (*a as u64) * (*b as u64) + carry;
+            prod[i + j] = v as u32;
+            carry = v >> 32;
+        }
+        prod[i + SCALAR_LIMBS] = carry as u32;
+    }
+    reduce(&prod)
+}
+
+/// Subtracts the group order from the provided value in place; the value must not be less than N.
+fn sub_order(a: &mut [u32; SCALAR_LIMBS]) {
+    let mut borrow = 0u64;
+    for (a, n) in a.iter_mut().zip(N.iter()) {
+        let (v, b0) = a.overflowing_sub(*n);
+        let (v, b1) = v.overflowing_sub(borrow as u32);
+        *a = v;
+        borrow = (b0 | b1) as u64;
+    }
+    debug_assert_eq!(borrow, 0);
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{is_canonical, mul_mod, reduce, Felt, Point, N, SCALAR_LIMBS};
+
+    #[test]
+    fn generator_has_order_n() {
+        let g = Point::generator();
+        assert_eq!(g.mul(&N), Point::neutral());
+        assert_ne!(g.double(), Point::neutral());
+        assert_eq!(g.encode(), [4, 0, 0, 0, 0].map(Felt::new));
+    }
+
+    #[test]
+    fn scalar_reduction() {
+        assert_eq!(reduce(&N), [0; SCALAR_LIMBS]);
+
+        let mut n_plus_one = N;
+        n_plus_one[0] += 1;
+        assert!(!is_canonical(&n_plus_one));
+        assert_eq!(reduce(&n_plus_one), one());
+
+        // (N - 1)^2 = 1 mod N
+        let mut n_minus_one = N;
+        n_minus_one[0] -= 1;
+        assert!(is_canonical(&n_minus_one));
+        assert_eq!(mul_mod(&n_minus_one, &n_minus_one), one());
+    }
+
+    #[test]
+    fn scalar_mul_is_homomorphic() {
+        let a = [7, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let b = [0, 3, 0, 0, 5, 0, 0, 0, 0, 0];
+        let g = Point::generator();
+        assert_eq!(g.mul(&a).mul(&b), g.mul(&mul_mod(&a, &b)));
+    }
+
+    fn one() -> [u32; SCALAR_LIMBS] {
+        let mut one = [0; SCALAR_LIMBS];
+        one[0] = 1;
+        one
+    }
+}
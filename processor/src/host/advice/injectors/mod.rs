@@ -1,5 +1,7 @@
 pub(super) mod adv_map_injectors;
 pub(super) mod adv_stack_injectors;
 pub(super) mod dsa;
+#[cfg(feature = "std")]
+pub(super) mod ecgfp5;
 pub(super) mod merkle_store_injectors;
 pub(super) mod smt;
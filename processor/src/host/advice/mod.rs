@@ -24,6 +24,11 @@ mod injectors;
 mod providers;
 pub use providers::{MemAdviceProvider, RecAdviceProvider};
 
+#[cfg(feature = "std")]
+mod disk;
+#[cfg(feature = "std")]
+pub use disk::DiskAdviceProvider;
+
 mod source;
 pub use source::AdviceSource;
 
@@ -64,11 +69,17 @@ pub trait AdviceProvider: Sized {
             } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset),
             AdviceInjector::UpdateMerkleNode => self.update_operand_stack_merkle_node(process),
             AdviceInjector::DivU64 => self.push_u64_div_result(process),
+            AdviceInjector::DivU256 => self.push_un_div_result(process, 8, 8),
+            AdviceInjector::DivUN {
+                dividend_limbs,
+                divisor_limbs,
+            } => self.push_un_div_result(process, *dividend_limbs, *divisor_limbs),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(process),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(process),
             AdviceInjector::SmtGet => self.push_smtget_inputs(process),
             AdviceInjector::SmtSet => self.push_smtset_inputs(process),
             AdviceInjector::SmtPeek => self.push_smtpeek_result(process),
+            AdviceInjector::MemLowerBound { stride } => self.push_mem_lower_bound(process, *stride),
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(process),
             AdviceInjector::HdwordToMap { domain } => {
                 self.insert_hdword_into_adv_map(process, *domain)
@@ -279,6 +290,39 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_u64_div_result(self, process)
     }
 
+    /// Pushes the result of division of two unsigned integers represented using 32-bit limbs
+    /// (both the quotient and the remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b_{n-1}, ..., b0, a_{m-1}, ..., a0, ...]
+    ///   Advice stack: [q0, ..., q_{m-1}, r0, ..., r_{n-1}, ...]
+    ///
+    /// Where m = `dividend_limbs` and n = `divisor_limbs`, and a0, b0, q0 and r0 are the least
+    /// significant limbs of the dividend, the divisor, the quotient and the remainder
+    /// respectively.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The divisor is ZERO.
+    /// - Any of the limbs is not a valid u32 value.
+    fn push_un_div_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+        dividend_limbs: usize,
+        divisor_limbs: usize,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_un_div_result(
+            self,
+            process,
+            dividend_limbs,
+            divisor_limbs,
+        )
+    }
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -483,6 +527,32 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtpeek_result(self, process)
     }
 
+    /// Pushes onto the advice stack the address of the first entry in a sorted array of words
+    /// which is not less than the specified key.
+    ///
+    /// Inputs:
+    ///   Operand stack: [KEY, start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [KEY, start_addr, end_addr, ...]
+    ///   Advice stack: [addr, ...]
+    ///
+    /// Where the array consists of entries of `stride` words located in
+    /// memory[start_addr..end_addr], and the first word of each entry is used as the sort key.
+    /// If all entries are less than `KEY`, `end_addr` is pushed onto the advice stack.
+    ///
+    /// # Errors
+    /// Returns an error if the memory range is invalid, or if its length is not a multiple of
+    /// `stride`.
+    fn push_mem_lower_bound<S: ProcessState>(
+        &mut self,
+        process: &S,
+        stride: usize,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_mem_lower_bound(self, process, stride)
+    }
+
     /// Pushes values onto the advice stack which are required for successful insertion of a
     /// key-value pair into a Sparse Merkle Tree data structure.
     ///
@@ -694,7 +764,10 @@ pub trait AdviceProvider: Sized {
     /// nodes which are descendants of the specified roots.
     ///
     /// The roots for which no descendants exist in this Merkle store are ignored.
-    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    ///
+    /// # Errors
+    /// Returns an error if the nodes of this Merkle store cannot be read.
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
     where
         I: Iterator<Item = R>,
         R: Borrow<RpoDigest>;
@@ -790,7 +863,7 @@ where
         T::merge_roots(self, lhs, rhs)
     }
 
-    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
     where
         I: Iterator<Item = R>,
         R: Borrow<RpoDigest>,
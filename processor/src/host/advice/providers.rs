@@ -120,6 +120,7 @@ where
 
         match kind {
             SignatureKind::RpoFalcon512 => injectors::dsa::falcon_sign(pk_sk, msg),
+            SignatureKind::SchnorrEcgfp5 => injectors::dsa::schnorr_ecgfp5_sign(pk_sk, msg),
         }
     }
 
@@ -225,12 +226,12 @@ where
             .map_err(ExecutionError::MerkleStoreMergeFailed)
     }
 
-    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
     where
         I: Iterator<Item = R>,
         R: core::borrow::Borrow<RpoDigest>,
     {
-        self.store.subset(roots).into_inner().into_iter().collect()
+        Ok(self.store.subset(roots).into_inner().into_iter().collect())
     }
 }
 
@@ -331,7 +332,7 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.merge_roots(lhs, rhs)
     }
 
-    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
         where
             I: Iterator<Item = R>,
             R: core::borrow::Borrow<RpoDigest> {
@@ -456,7 +457,7 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.merge_roots(lhs, rhs)
     }
 
-    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    fn get_store_subset<I, R>(&self, roots: I) -> Result<MerkleStore, ExecutionError>
         where
             I: Iterator<Item = R>,
             R: core::borrow::Borrow<RpoDigest> {
@@ -7,6 +7,9 @@ use advice::{AdviceExtractor, AdviceProvider};
 
 mod debug;
 
+#[cfg(test)]
+mod tests;
+
 // HOST TRAIT
 // ================================================================================================
 
@@ -19,6 +22,16 @@ mod debug;
 /// the VM in the form of [HostResponse]. The host is provided with a reference to the current
 /// state of the VM ([ProcessState]), which it can use to extract the data required to fulfill the
 /// request.
+///
+/// A host which cannot fulfill a request immediately (e.g., because the data has to be fetched
+/// from a remote source) may return [HostResponse::Pending]. In this case, the VM will invoke
+/// [Host::on_pending] and will then re-issue the same request; this repeats until the host returns
+/// a different response, or until [Host::is_cancelled] returns true. This allows hosts backed by
+/// asynchronous data sources to make progress on their pending work without blocking inside the
+/// request handlers.
+///
+/// Additionally, the VM checks [Host::is_cancelled] every [CANCELLATION_CHECK_INTERVAL] cycles,
+/// and aborts execution with [ExecutionError::ExecutionCancelled] if it returns true.
 pub trait Host {
     // REQUIRED METHODS
     // --------------------------------------------------------------------------------------------
@@ -64,12 +77,31 @@ pub trait Host {
         Ok(HostResponse::None)
     }
 
+    /// Handles a [HostResponse::Pending] response previously returned by this host.
+    ///
+    /// This method is invoked by the VM right before the pending request is re-issued, and is the
+    /// place for the host to drive its outstanding work (e.g., poll a future, or wait on a channel
+    /// with a timeout). By default, this does nothing.
+    fn on_pending<S: ProcessState>(&mut self, _process: &S) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    /// Returns true if the execution of the program should be aborted.
+    ///
+    /// The VM calls this method periodically during execution as well as while waiting on pending
+    /// requests. By default, execution is never cancelled.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
     /// Returns an error if the advice stack is empty.
     fn pop_adv_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
-        let response = self.get_advice(process, AdviceExtractor::PopStack)?;
+        let response = resolve_pending(self, process, |host| {
+            host.get_advice(process, AdviceExtractor::PopStack)
+        })?;
         Ok(response.into())
     }
 
@@ -81,7 +113,9 @@ pub trait Host {
     /// # Errors
     /// Returns an error if the advice stack does not contain a full word.
     fn pop_adv_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
-        let response = self.get_advice(process, AdviceExtractor::PopStackWord)?;
+        let response = resolve_pending(self, process, |host| {
+            host.get_advice(process, AdviceExtractor::PopStackWord)
+        })?;
         Ok(response.into())
     }
 
@@ -97,7 +131,9 @@ pub trait Host {
         &mut self,
         process: &S,
     ) -> Result<[Word; 2], ExecutionError> {
-        let response = self.get_advice(process, AdviceExtractor::PopStackDWord)?;
+        let response = resolve_pending(self, process, |host| {
+            host.get_advice(process, AdviceExtractor::PopStackDWord)
+        })?;
         Ok(response.into())
     }
 
@@ -114,7 +150,9 @@ pub trait Host {
         &mut self,
         process: &S,
     ) -> Result<MerklePath, ExecutionError> {
-        let response = self.get_advice(process, AdviceExtractor::GetMerklePath)?;
+        let response = resolve_pending(self, process, |host| {
+            host.get_advice(process, AdviceExtractor::GetMerklePath)
+        })?;
         Ok(response.into())
     }
 }
@@ -138,6 +176,61 @@ where
     ) -> Result<HostResponse, ExecutionError> {
         H::set_advice(self, process, injector)
     }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        H::on_debug(self, process, options)
+    }
+
+    fn on_pending<S: ProcessState>(&mut self, process: &S) -> Result<(), ExecutionError> {
+        H::on_pending(self, process)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        H::is_cancelled(self)
+    }
+}
+
+// PENDING REQUESTS
+// ================================================================================================
+
+/// Number of clock cycles between two consecutive checks of [Host::is_cancelled] performed by
+/// the VM during execution.
+pub const CANCELLATION_CHECK_INTERVAL: u32 = 1 << 12;
+
+/// Issues the specified request against the host until the host returns a response other than
+/// [HostResponse::Pending].
+///
+/// Between two consecutive attempts, [Host::on_pending] is invoked to let the host make progress
+/// on the pending request.
+///
+/// # Errors
+/// Returns an error if the request fails, or if the host signals cancellation while the request
+/// is pending.
+pub(crate) fn resolve_pending<H, S, F>(
+    host: &mut H,
+    process: &S,
+    mut request: F,
+) -> Result<HostResponse, ExecutionError>
+where
+    H: Host + ?Sized,
+    S: ProcessState,
+    F: FnMut(&mut H) -> Result<HostResponse, ExecutionError>,
+{
+    loop {
+        match request(host)? {
+            HostResponse::Pending => {
+                if host.is_cancelled() {
+                    return Err(ExecutionError::ExecutionCancelled(process.clk()));
+                }
+                host.on_pending(process)?;
+            }
+            response => return Ok(response),
+        }
+    }
 }
 
 // HOST RESPONSE
@@ -151,6 +244,9 @@ pub enum HostResponse {
     Word(Word),
     Element(Felt),
     None,
+    /// The request cannot be fulfilled yet; the VM will re-issue it after calling
+    /// [Host::on_pending].
+    Pending,
 }
 
 impl From<HostResponse> for MerklePath {
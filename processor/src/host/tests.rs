@@ -0,0 +1,132 @@
+use super::{
+    AdviceExtractor, AdviceInjector, DefaultHost, ExecutionError, Host, HostResponse,
+    MemAdviceProvider, ProcessState, CANCELLATION_CHECK_INTERVAL,
+};
+use crate::{AdviceInputs, ExecutionOptions, StackInputs, StackOutputs};
+use miden_assembly::Assembler;
+
+// PENDING REQUESTS
+// ================================================================================================
+
+#[test]
+fn pending_advice_requests_are_retried() {
+    let source = "begin adv_push.2 add end";
+    let mut host = DelayedHost::new(&[3, 5], 2);
+
+    let outputs = execute(source, &mut host).unwrap();
+    assert_eq!(outputs.stack()[0], 8);
+
+    // each of the two advice requests was reported as pending twice before being served
+    assert_eq!(host.num_pending_calls, 4);
+}
+
+#[test]
+fn pending_injector_requests_are_retried() {
+    let source = "begin push.5 push.3 adv.push_u64div adv_push.4 dropw drop drop end";
+    let mut host = DelayedHost::new(&[], 3);
+
+    execute(source, &mut host).unwrap();
+
+    // the injector and the four advice stack pops were all delayed
+    assert_eq!(host.num_pending_calls, 15);
+}
+
+#[test]
+fn pending_request_can_be_cancelled() {
+    let source = "begin adv_push.1 end";
+    let mut host = DelayedHost::new(&[1], usize::MAX);
+    host.cancel_after = Some(10);
+
+    let err = execute(source, &mut host).unwrap_err();
+    assert!(matches!(err, ExecutionError::ExecutionCancelled(_)));
+    assert_eq!(host.num_pending_calls, 10);
+}
+
+// CANCELLATION
+// ================================================================================================
+
+#[test]
+fn long_running_execution_can_be_cancelled() {
+    // the loop below never terminates
+    let source = "begin push.1 while.true push.1 end end";
+    let mut host = DelayedHost::new(&[], 0);
+    host.cancel_after = Some(0);
+
+    let err = execute(source, &mut host).unwrap_err();
+    match err {
+        ExecutionError::ExecutionCancelled(clk) => assert_eq!(clk, CANCELLATION_CHECK_INTERVAL),
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn execute(source: &str, host: &mut DelayedHost) -> Result<StackOutputs, ExecutionError> {
+    let program = Assembler::default().compile(source).unwrap();
+    crate::execute(&program, StackInputs::default(), host, ExecutionOptions::default())
+        .map(|trace| trace.stack_outputs().clone())
+}
+
+/// A host which reports every request as pending for a fixed number of attempts before handing
+/// it over to the wrapped [DefaultHost].
+struct DelayedHost {
+    inner: DefaultHost<MemAdviceProvider>,
+    delay: usize,
+    attempts: usize,
+    num_pending_calls: usize,
+    cancel_after: Option<usize>,
+}
+
+impl DelayedHost {
+    fn new(advice_stack: &[u64], delay: usize) -> Self {
+        let advice_inputs =
+            AdviceInputs::default().with_stack_values(advice_stack.iter().copied()).unwrap();
+        Self {
+            inner: DefaultHost::new(MemAdviceProvider::from(advice_inputs)),
+            delay,
+            attempts: 0,
+            num_pending_calls: 0,
+            cancel_after: None,
+        }
+    }
+
+    fn delay_request<F>(&mut self, request: F) -> Result<HostResponse, ExecutionError>
+    where
+        F: FnOnce(&mut DefaultHost<MemAdviceProvider>) -> Result<HostResponse, ExecutionError>,
+    {
+        if self.attempts < self.delay {
+            self.attempts += 1;
+            return Ok(HostResponse::Pending);
+        }
+        self.attempts = 0;
+        request(&mut self.inner)
+    }
+}
+
+impl Host for DelayedHost {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.delay_request(|host| host.get_advice(process, extractor))
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.delay_request(|host| host.set_advice(process, injector))
+    }
+
+    fn on_pending<S: ProcessState>(&mut self, _process: &S) -> Result<(), ExecutionError> {
+        self.num_pending_calls += 1;
+        Ok(())
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel_after.is_some_and(|limit| self.num_pending_calls >= limit)
+    }
+}
@@ -13,8 +13,8 @@ use miden_air::trace::{
 pub use miden_air::{ExecutionOptions, ExecutionOptionsError};
 pub use vm_core::{
     chiplets::hasher::Digest, errors::InputError, utils::DeserializationError, AdviceInjector,
-    AssemblyOp, Kernel, Operation, Program, ProgramInfo, QuadExtension, StackInputs, StackOutputs,
-    Word, EMPTY_WORD, ONE, ZERO,
+    AssemblyOp, Kernel, Operation, Program, ProgramInfo, QuadExtension, SignatureKind, StackInputs,
+    StackOutputs, Word, EMPTY_WORD, ONE, ZERO,
 };
 use vm_core::{
     code_blocks::{
@@ -44,15 +44,20 @@ use range::RangeChecker;
 mod host;
 pub use host::{
     advice::{AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider},
-    DefaultHost, Host,
+    DefaultHost, Host, HostResponse, CANCELLATION_CHECK_INTERVAL,
 };
 
+#[cfg(feature = "std")]
+pub use host::advice::DiskAdviceProvider;
+
 mod chiplets;
 use chiplets::Chiplets;
 
 mod trace;
 use trace::TraceFragment;
-pub use trace::{ChipletsLengths, ExecutionTrace, TraceLenSummary};
+pub use trace::{
+    ChipletsLengths, ConstraintViolation, ExecutionTrace, TraceLenSummary, TraceTable,
+};
 
 mod errors;
 pub use errors::{ExecutionError, Ext2InttError};
@@ -161,7 +166,7 @@ where
     range: RangeChecker,
     chiplets: Chiplets,
     host: RefCell<H>,
-    max_cycles: u32,
+    execution_options: ExecutionOptions,
 }
 
 impl<H> Process<H>
@@ -199,7 +204,7 @@ where
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             host: RefCell::new(host),
-            max_cycles: execution_options.max_cycles(),
+            execution_options,
         }
     }
 
@@ -322,8 +327,24 @@ where
             self.chiplets.access_kernel_proc(block.fn_hash())?;
         }
 
+        let start_clk = self.system.clk();
         self.start_call_block(block)?;
 
+        // bound the number of cycles the callee may consume, if a budget was specified; for
+        // dyncalls, the callee is the target of the dynamic call located on top of the stack
+        let mut call_budget_started = false;
+        if self.execution_options.has_call_cycle_limits() {
+            let callee = if block.fn_hash() == Dyn::dyn_hash() {
+                self.stack.get_word(0).into()
+            } else {
+                block.fn_hash()
+            };
+            if let Some(max_cycles) = self.execution_options.call_cycle_limit(&callee) {
+                self.system.start_call_budget(start_clk, max_cycles, callee);
+                call_budget_started = true;
+            }
+        }
+
         // if this is a dyncall, execute the dynamic code block
         if block.fn_hash() == Dyn::dyn_hash() {
             self.execute_dyn_block(&Dyn::new(), cb_table)?;
@@ -335,7 +356,12 @@ where
             self.execute_code_block(fn_body, cb_table)?;
         }
 
-        self.end_call_block(block)
+        self.end_call_block(block)?;
+
+        if call_budget_started {
+            self.system.end_call_budget();
+        }
+        Ok(())
     }
 
     /// Executes the specified [Dyn] block.
@@ -485,7 +511,8 @@ where
     fn execute_decorator(&mut self, decorator: &Decorator) -> Result<(), ExecutionError> {
         match decorator {
             Decorator::Advice(injector) => {
-                self.host.borrow_mut().set_advice(self, *injector)?;
+                let mut host = self.host.borrow_mut();
+                host::resolve_pending(&mut *host, self, |host| host.set_advice(self, *injector))?;
             }
             Decorator::Debug(options) => {
                 self.host.borrow_mut().on_debug(self, options)?;
@@ -593,5 +620,5 @@ where
     pub range: RangeChecker,
     pub chiplets: Chiplets,
     pub host: RefCell<H>,
-    pub max_cycles: u32,
+    pub execution_options: ExecutionOptions,
 }
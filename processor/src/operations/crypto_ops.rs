@@ -1,5 +1,5 @@
 use super::{ExecutionError, Host, Operation, Process};
-use crate::crypto::MerklePath;
+use crate::{crypto::MerklePath, host::resolve_pending};
 use vm_core::{AdviceInjector, StarkField};
 
 // CRYPTOGRAPHIC OPERATIONS
@@ -139,11 +139,10 @@ where
         // get a Merkle path to it. the length of the returned path is expected to match the
         // specified depth. if the new node is the root of a tree, this instruction will append the
         // whole sub-tree to this node.
-        let path: MerklePath = self
-            .host
-            .borrow_mut()
-            .set_advice(self, AdviceInjector::UpdateMerkleNode)?
-            .into();
+        let path: MerklePath = resolve_pending(&mut *self.host.borrow_mut(), self, |host| {
+            host.set_advice(self, AdviceInjector::UpdateMerkleNode)
+        })?
+        .into();
 
         assert_eq!(path.len(), depth.as_int() as usize);
 
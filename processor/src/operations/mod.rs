@@ -1,4 +1,7 @@
-use super::{ExecutionError, Felt, FieldElement, Host, Operation, Process, StarkField};
+use super::{
+    ExecutionError, Felt, FieldElement, Host, Operation, Process, StarkField,
+    CANCELLATION_CHECK_INTERVAL,
+};
 use vm_core::stack::STACK_TOP_SIZE;
 
 mod crypto_ops;
@@ -157,7 +160,14 @@ where
 
     /// Increments the clock cycle for all components of the process.
     fn advance_clock(&mut self) -> Result<(), ExecutionError> {
-        self.system.advance_clock(self.max_cycles)?;
+        self.system.advance_clock(self.execution_options.max_cycles())?;
+
+        // periodically give the host a chance to abort the execution
+        let clk = self.system.clk();
+        if clk % CANCELLATION_CHECK_INTERVAL == 0 && self.host.borrow().is_cancelled() {
+            return Err(ExecutionError::ExecutionCancelled(clk));
+        }
+
         self.stack.advance_clock();
         self.chiplets.advance_clock();
         Ok(())
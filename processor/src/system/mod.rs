@@ -1,5 +1,6 @@
 use super::{
-    ExecutionError, Felt, FieldElement, StarkField, SysTrace, Vec, Word, EMPTY_WORD, ONE, ZERO,
+    Digest, ExecutionError, Felt, FieldElement, StarkField, SysTrace, Vec, Word, EMPTY_WORD, ONE,
+    ZERO,
 };
 
 #[cfg(test)]
@@ -37,12 +38,17 @@ pub const FMP_MAX: u64 = 3 * 2_u64.pow(30) - 1;
 /// - in_syscall flag which indicates whether the execution is currently in a SYSCALL block.
 /// - hash of the function which initiated the current execution context. if the context was
 ///   initiated from the root context, this will be set to ZEROs.
+///
+/// It also keeps track of the cycle budgets of the currently executing CALL and SYSCALL blocks,
+/// if such budgets were specified.
 pub struct System {
     clk: u32,
     ctx: u32,
     fmp: Felt,
     in_syscall: bool,
     fn_hash: Word,
+    call_budgets: Vec<CallBudget>,
+    call_deadline: u32,
     ctx_trace: Vec<Felt>,
     clk_trace: Vec<Felt>,
     fmp_trace: Vec<Felt>,
@@ -68,6 +74,8 @@ impl System {
             fmp,
             in_syscall: false,
             fn_hash: EMPTY_WORD,
+            call_budgets: Vec::new(),
+            call_deadline: u32::MAX,
             clk_trace: Felt::zeroed_vector(init_trace_capacity),
             ctx_trace: Felt::zeroed_vector(init_trace_capacity),
             fmp_trace,
@@ -145,6 +153,11 @@ impl System {
             return Err(ExecutionError::CycleLimitExceeded(max_cycles));
         }
 
+        // Check that none of the currently executing calls exceeded its cycle budget.
+        if self.clk > self.call_deadline {
+            return Err(self.call_budget_error());
+        }
+
         let clk = self.clk as usize;
 
         self.clk_trace[clk] = Felt::from(self.clk);
@@ -215,6 +228,43 @@ impl System {
         self.fn_hash = fn_hash;
     }
 
+    // CALL BUDGETS
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts tracking the cycle budget of a CALL or SYSCALL block which started at the specified
+    /// clock cycle and which is executed in the current execution context.
+    ///
+    /// After this, advancing the clock past `start_clk + max_cycles` results in an error until
+    /// the budget is released via [System::end_call_budget()].
+    pub fn start_call_budget(&mut self, start_clk: u32, max_cycles: u32, callee: Digest) {
+        let deadline = start_clk.saturating_add(max_cycles);
+        self.call_budgets.push(CallBudget {
+            deadline,
+            ctx: self.ctx,
+            callee,
+            max_cycles,
+        });
+        self.call_deadline = self.call_deadline.min(deadline);
+    }
+
+    /// Stops tracking the cycle budget of the most recently started CALL or SYSCALL block.
+    pub fn end_call_budget(&mut self) {
+        self.call_budgets.pop().expect("no call budget");
+        self.call_deadline =
+            self.call_budgets.iter().map(|budget| budget.deadline).min().unwrap_or(u32::MAX);
+    }
+
+    /// Returns the error for the outermost call whose cycle budget has been exceeded.
+    fn call_budget_error(&self) -> ExecutionError {
+        let budget = self
+            .call_budgets
+            .iter()
+            .find(|budget| budget.deadline == self.call_deadline)
+            .expect("no exceeded call budget");
+
+        ExecutionError::CallCycleLimitExceeded(budget.ctx, budget.callee, budget.max_cycles)
+    }
+
     // TRACE GENERATIONS
     // --------------------------------------------------------------------------------------------
 
@@ -296,3 +346,14 @@ impl System {
         }
     }
 }
+
+// CALL BUDGET
+// ================================================================================================
+
+/// Cycle budget of an execution context initiated by a CALL or a SYSCALL block.
+struct CallBudget {
+    deadline: u32,
+    ctx: u32,
+    callee: Digest,
+    max_cycles: u32,
+}
@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::{DefaultHost, ExecutionOptions, Kernel, Operation, Process, StackInputs};
+    use crate::{
+        DefaultHost, ExecutionError, ExecutionOptions, Kernel, Operation, Process, StackInputs,
+        StackOutputs,
+    };
+    use miden_assembly::Assembler;
+    use vm_core::{code_blocks::CodeBlock, Program};
 
     // Check that process returns an error if a maximum number of cycles is exceeded.
     #[test]
@@ -18,4 +23,150 @@ mod tests {
         }
         assert!(process.execute_op(Operation::Noop).is_err());
     }
+
+    // Check that calls which stay within their cycle budget are executed normally.
+    #[test]
+    fn call_within_budget() {
+        let source = "
+            proc.foo
+                push.1 push.2 add swap drop
+            end
+
+            begin
+                call.foo
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+
+        let options = ExecutionOptions::default().with_max_call_cycles(64);
+        let outputs = execute(&program, options).unwrap();
+        assert_eq!(outputs.stack()[0], 3);
+    }
+
+    // Check that a call which exceeds its cycle budget fails with an error which reports the
+    // context and the MAST root of the callee.
+    #[test]
+    fn call_budget_exceeded() {
+        let source = "
+            proc.foo
+                repeat.100 push.1 drop end
+            end
+
+            begin
+                call.foo
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+        let callee = call_target(&program);
+
+        // without a call budget, the program executes successfully
+        assert!(execute(&program, ExecutionOptions::default()).is_ok());
+
+        let options = ExecutionOptions::default().with_max_call_cycles(64);
+        match execute(&program, options).unwrap_err() {
+            ExecutionError::CallCycleLimitExceeded(ctx, err_callee, max_cycles) => {
+                assert_ne!(ctx, 0);
+                assert_eq!(err_callee, callee);
+                assert_eq!(max_cycles, 64);
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    // Check that the budget of an outer call covers the cycles consumed by nested calls.
+    #[test]
+    fn nested_call_budget_exceeded() {
+        let source = "
+            proc.bar
+                repeat.30 push.1 drop end
+            end
+
+            proc.foo
+                call.bar
+                call.bar
+            end
+
+            begin
+                call.foo
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+        let outer = call_target(&program);
+
+        // each call to bar fits into the budget, but the call to foo does not
+        let options = ExecutionOptions::default().with_max_call_cycles(128);
+        match execute(&program, options).unwrap_err() {
+            ExecutionError::CallCycleLimitExceeded(_, callee, _) => assert_eq!(callee, outer),
+            err => panic!("unexpected error: {err}"),
+        }
+
+        let options = ExecutionOptions::default().with_max_call_cycles(256);
+        assert!(execute(&program, options).is_ok());
+    }
+
+    // Check that limits set for specific callees take precedence over the default call budget.
+    #[test]
+    fn call_cycle_limit_per_callee() {
+        let source = "
+            proc.trusted
+                repeat.100 push.1 drop end
+            end
+
+            proc.untrusted
+                repeat.10 push.1 drop end
+            end
+
+            begin
+                call.trusted
+                call.untrusted
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+        let (trusted, untrusted) = match program.root() {
+            CodeBlock::Join(block) => match (block.first(), block.second()) {
+                (CodeBlock::Call(first), CodeBlock::Call(second)) => {
+                    (first.fn_hash(), second.fn_hash())
+                }
+                _ => panic!("expected two call blocks"),
+            },
+            _ => panic!("expected a join block"),
+        };
+
+        // the default budget is too small for the trusted procedure, but its own limit is not
+        let options = ExecutionOptions::default()
+            .with_max_call_cycles(64)
+            .with_call_cycle_limit(trusted, 512);
+        assert!(execute(&program, options).is_ok());
+
+        // the untrusted procedure exceeds its own limit even though the default budget is large
+        let options = ExecutionOptions::default()
+            .with_max_call_cycles(512)
+            .with_call_cycle_limit(untrusted, 16);
+        match execute(&program, options).unwrap_err() {
+            ExecutionError::CallCycleLimitExceeded(_, callee, max_cycles) => {
+                assert_eq!(callee, untrusted);
+                assert_eq!(max_cycles, 16);
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+
+        // without a default budget, only the callees with their own limits are bounded
+        let options = ExecutionOptions::default().with_call_cycle_limit(untrusted, 256);
+        assert!(execute(&program, options).is_ok());
+        let options = ExecutionOptions::default().with_call_cycle_limit(trusted, 64);
+        assert!(execute(&program, options).is_err());
+    }
+
+    fn execute(
+        program: &Program,
+        options: ExecutionOptions,
+    ) -> Result<StackOutputs, ExecutionError> {
+        crate::execute(program, StackInputs::default(), DefaultHost::default(), options)
+            .map(|trace| trace.stack_outputs().clone())
+    }
+
+    /// Returns the MAST root of the procedure called by the program, if the program consists of a
+    /// single CALL block.
+    fn call_target(program: &Program) -> vm_core::crypto::hash::RpoDigest {
+        match program.root() {
+            CodeBlock::Call(block) => block.fn_hash(),
+            _ => panic!("expected a call block"),
+        }
+    }
 }
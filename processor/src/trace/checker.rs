@@ -0,0 +1,391 @@
+use super::{ExecutionTrace, Felt, FieldElement, QuadFelt, Trace, Vec};
+use core::fmt;
+use miden_air::{
+    trace::{aux_trace_column_names, main_trace_column_names, AUX_TRACE_RAND_ELEMENTS},
+    ProcessorAir, ProvingOptions, PublicInputs,
+};
+use vm_core::{
+    utils::string::{String, ToString},
+    StackInputs,
+};
+use winter_prover::{Air, Assertion, AuxTraceRandElements, ColMatrix, EvaluationFrame};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Value added to a trace cell to find out whether a constraint depends on that cell.
+const PERTURBATION: Felt = Felt::new(0x5a5a_5a5a_5a5a_5a5a);
+
+// CONSTRAINT VIOLATION
+// ================================================================================================
+
+/// Describes the first constraint of [ProcessorAir] which is not satisfied by an execution trace.
+///
+/// Values of the main trace columns are lifted into the extension field so that main and
+/// auxiliary columns can be reported uniformly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstraintViolation {
+    /// A boundary constraint is not satisfied: the value in the specified column at the specified
+    /// row differs from the expected one.
+    Boundary {
+        column: String,
+        row: usize,
+        expected: QuadFelt,
+        actual: QuadFelt,
+    },
+    /// A transition constraint between the specified row and the next one does not evaluate to
+    /// zero.
+    ///
+    /// `columns` contains the name and the values at both rows of every column on which the
+    /// evaluation of the constraint depends.
+    Transition {
+        name: String,
+        row: usize,
+        evaluation: QuadFelt,
+        columns: Vec<(String, QuadFelt, QuadFelt)>,
+    },
+}
+
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boundary {
+                column,
+                row,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "boundary constraint on column {column} failed at row {row}: expected {}, but was {}",
+                    display_value(*expected),
+                    display_value(*actual)
+                )
+            }
+            Self::Transition {
+                name,
+                row,
+                evaluation,
+                columns,
+            } => {
+                write!(
+                    f,
+                    "transition constraint {name} failed at row {row} (evaluated to {})",
+                    display_value(*evaluation)
+                )?;
+                for (column, current, next) in columns {
+                    write!(
+                        f,
+                        "\n  {column}: {} -> {}",
+                        display_value(*current),
+                        display_value(*next)
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConstraintViolation {}
+
+// CONSTRAINT CHECKER
+// ================================================================================================
+
+impl ExecutionTrace {
+    /// Evaluates the boundary and transition constraints of [ProcessorAir] against this trace
+    /// row-by-row, and returns the first constraint which is not satisfied.
+    ///
+    /// Constraints are checked in the following order: boundary constraints of the main trace,
+    /// transition constraints of the main trace, boundary constraints of the auxiliary trace, and
+    /// transition constraints of the auxiliary trace. The auxiliary trace is built using the
+    /// provided random elements.
+    ///
+    /// This is an expensive operation intended for debugging only.
+    ///
+    /// # Errors
+    /// Returns an error describing the first violated constraint, if any.
+    ///
+    /// # Panics
+    /// Panics if fewer than [AUX_TRACE_RAND_ELEMENTS] random elements are provided.
+    pub fn check_constraints(
+        &mut self,
+        stack_inputs: StackInputs,
+        rand_elements: &[QuadFelt],
+    ) -> Result<(), ConstraintViolation> {
+        assert!(
+            rand_elements.len() >= AUX_TRACE_RAND_ELEMENTS,
+            "expected at least {AUX_TRACE_RAND_ELEMENTS} random elements, but received {}",
+            rand_elements.len()
+        );
+
+        let pub_inputs =
+            PublicInputs::new(self.program_info.clone(), stack_inputs, self.stack_outputs.clone());
+        let air = ProcessorAir::new(self.get_info(), pub_inputs, ProvingOptions::default().into());
+
+        let aux_trace =
+            self.build_aux_segment(&[], rand_elements).expect("failed to build aux segment");
+        let mut aux_rand_elements = AuxTraceRandElements::new();
+        aux_rand_elements.add_segment_elements(rand_elements.to_vec());
+
+        let checker = ConstraintChecker {
+            air: &air,
+            main_trace: &self.main_trace,
+            aux_trace: &aux_trace,
+            aux_rand_elements: &aux_rand_elements,
+            periodic_columns: air.get_periodic_column_values(),
+            main_column_names: main_trace_column_names(),
+            aux_column_names: aux_trace_column_names(),
+        };
+
+        checker.check_boundary_constraints(&air.get_assertions(), false)?;
+        checker.check_main_transitions()?;
+        checker.check_boundary_constraints(&air.get_aux_assertions(&aux_rand_elements), true)?;
+        checker.check_aux_transitions()
+    }
+}
+
+/// Evaluates constraints of [ProcessorAir] against concrete main and auxiliary traces.
+struct ConstraintChecker<'a> {
+    air: &'a ProcessorAir,
+    main_trace: &'a ColMatrix<Felt>,
+    aux_trace: &'a ColMatrix<QuadFelt>,
+    aux_rand_elements: &'a AuxTraceRandElements<QuadFelt>,
+    periodic_columns: Vec<Vec<Felt>>,
+    main_column_names: Vec<String>,
+    aux_column_names: Vec<String>,
+}
+
+impl<'a> ConstraintChecker<'a> {
+    // BOUNDARY CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the provided assertions against either the main or the auxiliary trace.
+    fn check_boundary_constraints<E>(
+        &self,
+        assertions: &[Assertion<E>],
+        is_aux: bool,
+    ) -> Result<(), ConstraintViolation>
+    where
+        E: FieldElement<BaseField = Felt>,
+        QuadFelt: From<E>,
+    {
+        let mut result = Ok(());
+        for assertion in assertions {
+            assertion.apply(self.main_trace.num_rows(), |row, expected| {
+                if result.is_err() {
+                    return;
+                }
+                let column = assertion.column();
+                let (name, actual) = if is_aux {
+                    (&self.aux_column_names[column], self.aux_trace.get(column, row))
+                } else {
+                    (&self.main_column_names[column], self.main_trace.get(column, row).into())
+                };
+                let expected = QuadFelt::from(expected);
+                if actual != expected {
+                    result = Err(ConstraintViolation::Boundary {
+                        column: name.clone(),
+                        row,
+                        expected,
+                        actual,
+                    });
+                }
+            });
+            if result.is_err() {
+                break;
+            }
+        }
+        result
+    }
+
+    // TRANSITION CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks transition constraints of the main trace at all rows which are not exempt from them.
+    fn check_main_transitions(&self) -> Result<(), ConstraintViolation> {
+        let names = ProcessorAir::get_transition_constraint_names();
+        let mut evaluations =
+            vec![Felt::ZERO; self.air.context().num_main_transition_constraints()];
+
+        for row in 0..self.num_transition_rows() {
+            let frame = self.main_frame(row);
+            let periodic_values = self.periodic_values(row);
+            self.air.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+
+            if let Some(idx) = evaluations.iter().position(|&value| value != Felt::ZERO) {
+                // find the columns on which the failing constraint depends
+                let mut columns = Vec::new();
+                for column in 0..frame.current().len() {
+                    let depends_on_column = [false, true].into_iter().any(|is_next| {
+                        let mut frame = frame.clone();
+                        perturb(&mut frame, column, is_next, PERTURBATION);
+                        let mut perturbed = vec![Felt::ZERO; evaluations.len()];
+                        self.air.evaluate_transition(&frame, &periodic_values, &mut perturbed);
+                        perturbed[idx] != evaluations[idx]
+                    });
+                    if depends_on_column {
+                        columns.push((
+                            self.main_column_names[column].clone(),
+                            frame.current()[column].into(),
+                            frame.next()[column].into(),
+                        ));
+                    }
+                }
+
+                return Err(ConstraintViolation::Transition {
+                    name: names[idx].clone(),
+                    row,
+                    evaluation: evaluations[idx].into(),
+                    columns,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks transition constraints of the auxiliary trace at all rows which are not exempt from
+    /// them.
+    fn check_aux_transitions(&self) -> Result<(), ConstraintViolation> {
+        let names = ProcessorAir::get_aux_transition_constraint_names();
+        let num_constraints = self.air.context().num_aux_transition_constraints();
+        let mut evaluations = vec![QuadFelt::ZERO; num_constraints];
+
+        for row in 0..self.num_transition_rows() {
+            let main_frame = self.main_frame(row);
+            let aux_frame = self.aux_frame(row);
+            let periodic_values = self.periodic_values(row);
+            self.evaluate_aux_transition(
+                &main_frame,
+                &aux_frame,
+                &periodic_values,
+                &mut evaluations,
+            );
+
+            if let Some(idx) = evaluations.iter().position(|&value| value != QuadFelt::ZERO) {
+                // find the columns of both traces on which the failing constraint depends
+                let mut columns = Vec::new();
+                let mut perturbed = vec![QuadFelt::ZERO; num_constraints];
+                for column in 0..main_frame.current().len() {
+                    let depends_on_column = [false, true].into_iter().any(|is_next| {
+                        let mut frame = main_frame.clone();
+                        perturb(&mut frame, column, is_next, PERTURBATION);
+                        self.evaluate_aux_transition(
+                            &frame,
+                            &aux_frame,
+                            &periodic_values,
+                            &mut perturbed,
+                        );
+                        perturbed[idx] != evaluations[idx]
+                    });
+                    if depends_on_column {
+                        columns.push((
+                            self.main_column_names[column].clone(),
+                            main_frame.current()[column].into(),
+                            main_frame.next()[column].into(),
+                        ));
+                    }
+                }
+                for column in 0..aux_frame.current().len() {
+                    let depends_on_column = [false, true].into_iter().any(|is_next| {
+                        let mut frame = aux_frame.clone();
+                        perturb(&mut frame, column, is_next, PERTURBATION.into());
+                        self.evaluate_aux_transition(
+                            &main_frame,
+                            &frame,
+                            &periodic_values,
+                            &mut perturbed,
+                        );
+                        perturbed[idx] != evaluations[idx]
+                    });
+                    if depends_on_column {
+                        columns.push((
+                            self.aux_column_names[column].clone(),
+                            aux_frame.current()[column],
+                            aux_frame.next()[column],
+                        ));
+                    }
+                }
+
+                return Err(ConstraintViolation::Transition {
+                    name: names[idx].clone(),
+                    row,
+                    evaluation: evaluations[idx],
+                    columns,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of rows at which transition constraints are enforced.
+    fn num_transition_rows(&self) -> usize {
+        self.main_trace.num_rows() - self.air.context().num_transition_exemptions()
+    }
+
+    /// Returns the values of periodic columns at the specified row.
+    fn periodic_values(&self, row: usize) -> Vec<Felt> {
+        self.periodic_columns.iter().map(|column| column[row % column.len()]).collect()
+    }
+
+    /// Returns the main trace evaluation frame consisting of the specified row and the next one.
+    fn main_frame(&self, row: usize) -> EvaluationFrame<Felt> {
+        let mut frame = EvaluationFrame::new(self.main_trace.num_cols());
+        self.main_trace.read_row_into(row, frame.current_mut());
+        self.main_trace.read_row_into(row + 1, frame.next_mut());
+        frame
+    }
+
+    /// Returns the auxiliary trace evaluation frame consisting of the specified row and the next
+    /// one.
+    fn aux_frame(&self, row: usize) -> EvaluationFrame<QuadFelt> {
+        let mut frame = EvaluationFrame::new(self.aux_trace.num_cols());
+        self.aux_trace.read_row_into(row, frame.current_mut());
+        self.aux_trace.read_row_into(row + 1, frame.next_mut());
+        frame
+    }
+
+    fn evaluate_aux_transition(
+        &self,
+        main_frame: &EvaluationFrame<Felt>,
+        aux_frame: &EvaluationFrame<QuadFelt>,
+        periodic_values: &[Felt],
+        result: &mut [QuadFelt],
+    ) {
+        self.air.evaluate_aux_transition(
+            main_frame,
+            aux_frame,
+            periodic_values,
+            self.aux_rand_elements,
+            result,
+        );
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds the specified value to a cell of either the current or the next row of the frame.
+fn perturb<E: FieldElement>(frame: &mut EvaluationFrame<E>, column: usize, is_next: bool, by: E) {
+    if is_next {
+        frame.next_mut()[column] += by;
+    } else {
+        frame.current_mut()[column] += by;
+    }
+}
+
+/// Formats an extension field element, omitting the second coefficient when it is zero.
+fn display_value(value: QuadFelt) -> String {
+    let [a, b] = value.to_base_elements();
+    if b == Felt::ZERO {
+        a.to_string()
+    } else {
+        format!("({a}, {b})")
+    }
+}
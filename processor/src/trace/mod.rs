@@ -3,12 +3,13 @@ use super::{
     decoder::AuxTraceHints as DecoderAuxTraceHints,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, Digest, Felt, FieldElement, Host,
-    Process, StackTopState, Vec,
+    Process, QuadFelt, StackTopState, Vec,
 };
 use miden_air::trace::{
+    aux_trace_column_names,
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
-    AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
-    STACK_TRACE_OFFSET, TRACE_WIDTH,
+    main_trace_column_names, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET,
+    MIN_TRACE_LEN, STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
 use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
@@ -24,6 +25,12 @@ pub use utils::{
 
 mod decoder;
 
+mod table;
+pub use table::TraceTable;
+
+mod checker;
+pub use checker::ConstraintViolation;
+
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
@@ -155,6 +162,44 @@ impl ExecutionTrace {
         &self.trace_len_summary
     }
 
+    /// Returns the main segment of this trace as a [TraceTable] with named columns.
+    pub fn main_trace_table(&self) -> TraceTable {
+        let columns = self.main_trace.columns().map(|column| column.to_vec()).collect();
+        TraceTable::new(main_trace_column_names(), columns)
+    }
+
+    /// Returns the main and the auxiliary segments of this trace as a [TraceTable] with named
+    /// columns.
+    ///
+    /// The auxiliary segment is built using the provided random elements. Each auxiliary column
+    /// is split into two base field columns holding the coefficients of its extension field
+    /// elements; the names of these columns are suffixed with `.0` and `.1` respectively.
+    ///
+    /// # Panics
+    /// Panics if fewer than [AUX_TRACE_RAND_ELEMENTS] random elements are provided.
+    pub fn trace_table_with_aux(&mut self, rand_elements: &[QuadFelt]) -> TraceTable {
+        assert!(
+            rand_elements.len() >= AUX_TRACE_RAND_ELEMENTS,
+            "expected at least {AUX_TRACE_RAND_ELEMENTS} random elements, but received {}",
+            rand_elements.len()
+        );
+
+        let aux_trace =
+            self.build_aux_segment(&[], rand_elements).expect("failed to build aux segment");
+
+        let mut column_names = main_trace_column_names();
+        let mut columns: Vec<Vec<Felt>> =
+            self.main_trace.columns().map(|column| column.to_vec()).collect();
+        for (name, column) in aux_trace_column_names().into_iter().zip(aux_trace.columns()) {
+            for i in 0..2 {
+                column_names.push(format!("{name}.{i}"));
+                columns.push(column.iter().map(|value| value.to_base_elements()[i]).collect());
+            }
+        }
+
+        TraceTable::new(column_names, columns)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -0,0 +1,257 @@
+use super::{Felt, Vec};
+use vm_core::utils::{
+    string::{String, ToString},
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+#[cfg(feature = "std")]
+use vm_core::StarkField;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the binary serialization format of [TraceTable].
+const TRACE_TABLE_VERSION: u8 = 1;
+
+/// Maximum number of column names, columns, or column values for which memory is allocated up
+/// front when deserializing a [TraceTable]; the header of the serialized table is untrusted, and
+/// thus, larger vectors grow only as their elements are actually read.
+const MAX_PREALLOCATED_LEN: usize = 1 << 16;
+
+// TRACE TABLE
+// ================================================================================================
+
+/// A set of named execution trace columns which can be exported and loaded back for offline
+/// analysis (e.g., to diff traces generated by different versions of the VM).
+///
+/// Column names follow the layout described by [miden_air::trace::main_trace_column_names()] and
+/// [miden_air::trace::aux_trace_column_names()]. Auxiliary columns are defined over the quadratic
+/// extension field; each of these columns is split into two base field columns named with `.0`
+/// and `.1` suffixes.
+///
+/// The table can be serialized into a compact binary format via [Serializable], and, with the
+/// `std` feature enabled, it can also be written to or read from CSV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceTable {
+    column_names: Vec<String>,
+    columns: Vec<Vec<Felt>>,
+}
+
+impl TraceTable {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TraceTable] instantiated from the specified column names and columns.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The number of column names is different from the number of columns.
+    /// - Not all columns have the same length.
+    pub fn new(column_names: Vec<String>, columns: Vec<Vec<Felt>>) -> Self {
+        assert_eq!(column_names.len(), columns.len(), "inconsistent number of column names");
+        if let Some(first) = columns.first() {
+            assert!(
+                columns.iter().all(|column| column.len() == first.len()),
+                "all columns must have the same length"
+            );
+        }
+
+        Self {
+            column_names,
+            columns,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in this table.
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the number of rows in this table.
+    pub fn num_rows(&self) -> usize {
+        self.columns.first().map_or(0, |column| column.len())
+    }
+
+    /// Returns the names of the columns in this table.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Returns the column at the specified index.
+    pub fn get_column(&self, idx: usize) -> &[Felt] {
+        &self.columns[idx]
+    }
+
+    /// Returns the column with the specified name, or None if the table contains no such column.
+    pub fn get_column_by_name(&self, name: &str) -> Option<&[Felt]> {
+        self.column_names
+            .iter()
+            .position(|column_name| column_name == name)
+            .map(|idx| self.columns[idx].as_slice())
+    }
+
+    /// Returns the values of all columns at the specified row.
+    pub fn get_row(&self, row_idx: usize) -> Vec<Felt> {
+        self.columns.iter().map(|column| column[row_idx]).collect()
+    }
+
+    /// Returns the row index and the name of the column of the first cell at which this table
+    /// differs from the other table, or None if the tables are identical.
+    ///
+    /// Cells are compared row by row, and columns are matched by name. A column present in only
+    /// one of the tables is reported as differing at row 0; if the tables have a different number
+    /// of rows, the first row missing from the shorter table is reported as differing.
+    pub fn first_difference<'a>(&'a self, other: &'a Self) -> Option<(usize, &'a str)> {
+        for name in self.column_names.iter() {
+            if other.get_column_by_name(name).is_none() {
+                return Some((0, name));
+            }
+        }
+        for name in other.column_names.iter() {
+            if self.get_column_by_name(name).is_none() {
+                return Some((0, name));
+            }
+        }
+
+        let num_rows = self.num_rows().min(other.num_rows());
+        for row_idx in 0..num_rows {
+            for (name, column) in self.column_names.iter().zip(self.columns.iter()) {
+                let other_column = other.get_column_by_name(name).expect("missing column");
+                if column[row_idx] != other_column[row_idx] {
+                    return Some((row_idx, name));
+                }
+            }
+        }
+
+        if self.num_rows() != other.num_rows() {
+            return self.column_names.first().map(|name| (num_rows, name.as_str()));
+        }
+
+        None
+    }
+
+    // CSV
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes this table into the specified writer in CSV format.
+    ///
+    /// The first line contains column names, and each subsequent line contains the values of a
+    /// single row, with field elements written as canonical integers.
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "{}", self.column_names.join(","))?;
+        for row_idx in 0..self.num_rows() {
+            let row = self.columns.iter().map(|column| column[row_idx].as_int().to_string());
+            writeln!(writer, "{}", row.collect::<Vec<_>>().join(","))?;
+        }
+        writer.flush()
+    }
+
+    /// Reads a table in CSV format (as written by [TraceTable::write_csv()]) from the specified
+    /// reader.
+    ///
+    /// # Errors
+    /// Returns an error if the data could not be read, or if it is not a valid trace table.
+    #[cfg(feature = "std")]
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, DeserializationError> {
+        let mut lines = reader.lines();
+        let header = lines
+            .next()
+            .ok_or(DeserializationError::UnexpectedEOF)?
+            .map_err(|err| DeserializationError::UnknownError(err.to_string()))?;
+        let column_names: Vec<String> = header.split(',').map(String::from).collect();
+        let mut columns = vec![Vec::new(); column_names.len()];
+
+        for (line_idx, line) in lines.enumerate() {
+            let line = line.map_err(|err| DeserializationError::UnknownError(err.to_string()))?;
+            let values = line.split(',').collect::<Vec<_>>();
+            if values.len() != column_names.len() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "expected {} values in row {line_idx}, but found {}",
+                    column_names.len(),
+                    values.len()
+                )));
+            }
+            for (column, value) in columns.iter_mut().zip(values) {
+                let value = value
+                    .trim()
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|&value| value < Felt::MODULUS)
+                    .ok_or_else(|| {
+                        DeserializationError::InvalidValue(format!(
+                            "invalid field element {value} in row {line_idx}"
+                        ))
+                    })?;
+                column.push(Felt::new(value));
+            }
+        }
+
+        Ok(Self {
+            column_names,
+            columns,
+        })
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TraceTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(TRACE_TABLE_VERSION);
+        target.write_u32(self.num_columns() as u32);
+        target.write_u64(self.num_rows() as u64);
+        for name in self.column_names.iter() {
+            target.write_u32(name.len() as u32);
+            target.write_bytes(name.as_bytes());
+        }
+        for column in self.columns.iter() {
+            for &value in column.iter() {
+                target.write(value);
+            }
+        }
+    }
+}
+
+impl Deserializable for TraceTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != TRACE_TABLE_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported trace table version {version}"
+            )));
+        }
+
+        let num_columns = source.read_u32()? as usize;
+        let num_rows = usize::try_from(source.read_u64()?).map_err(|_| {
+            DeserializationError::InvalidValue("number of rows is too large".to_string())
+        })?;
+
+        let mut column_names = Vec::with_capacity(num_columns.min(MAX_PREALLOCATED_LEN));
+        for _ in 0..num_columns {
+            let len = source.read_u32()? as usize;
+            let name = String::from_utf8(source.read_vec(len)?).map_err(|_| {
+                DeserializationError::InvalidValue("invalid column name".to_string())
+            })?;
+            column_names.push(name);
+        }
+
+        let mut columns = Vec::with_capacity(num_columns.min(MAX_PREALLOCATED_LEN));
+        for _ in 0..num_columns {
+            let mut column = Vec::with_capacity(num_rows.min(MAX_PREALLOCATED_LEN));
+            for _ in 0..num_rows {
+                column.push(Felt::read_from(source)?);
+            }
+            columns.push(column);
+        }
+
+        Ok(Self {
+            column_names,
+            columns,
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use super::{build_trace_from_ops, ExecutionTrace, Felt, Trace, ONE};
+use crate::{ConstraintViolation, DefaultHost, ExecutionOptions, QuadFelt};
+use miden_air::{
+    trace::{AUX_TRACE_RAND_ELEMENTS, CLK_COL_IDX, STACK_TRACE_OFFSET},
+    ProcessorAir, ProvingOptions, PublicInputs,
+};
+use miden_assembly::Assembler;
+use test_utils::rand::rand_array;
+use vm_core::{Operation, StackInputs, StackOutputs};
+use winter_prover::Air;
+
+// CONSTRAINT NAMES
+// ================================================================================================
+
+#[test]
+fn constraint_names_match_air() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[1, 2]);
+    let pub_inputs = PublicInputs::new(
+        trace.program_info().clone(),
+        StackInputs::default(),
+        StackOutputs::default(),
+    );
+    let air = ProcessorAir::new(trace.get_info(), pub_inputs, ProvingOptions::default().into());
+
+    assert_eq!(
+        ProcessorAir::get_transition_constraint_names().len(),
+        air.context().num_main_transition_constraints()
+    );
+    assert_eq!(
+        ProcessorAir::get_aux_transition_constraint_names().len(),
+        air.context().num_aux_transition_constraints()
+    );
+}
+
+// CONSTRAINT CHECKER
+// ================================================================================================
+
+#[test]
+fn valid_trace_satisfies_constraints() {
+    let (mut trace, stack_inputs) = build_trace(&[1, 255]);
+    assert_eq!(trace.check_constraints(stack_inputs, &rand_elements()), Ok(()));
+}
+
+#[test]
+fn boundary_violation_is_reported() {
+    let (mut trace, stack_inputs) = build_trace(&[1, 255]);
+    set_cell(&mut trace, CLK_COL_IDX, 0, ONE);
+
+    let err = trace.check_constraints(stack_inputs, &rand_elements()).unwrap_err();
+    assert_eq!(
+        err,
+        ConstraintViolation::Boundary {
+            column: "clk".to_string(),
+            row: 0,
+            expected: QuadFelt::from(Felt::new(0)),
+            actual: QuadFelt::from(ONE),
+        }
+    );
+}
+
+#[test]
+fn transition_violation_is_reported() {
+    let (mut trace, stack_inputs) = build_trace(&[1, 255]);
+
+    // corrupt the value of clk at row 3; this breaks the transition between rows 2 and 3
+    set_cell(&mut trace, CLK_COL_IDX, 3, Felt::new(42));
+
+    let err = trace.check_constraints(stack_inputs, &rand_elements()).unwrap_err();
+    match err {
+        ConstraintViolation::Transition {
+            name, row, columns, ..
+        } => {
+            assert_eq!(name, "system.clk");
+            assert_eq!(row, 2);
+            assert_eq!(
+                columns,
+                vec![(
+                    "clk".to_string(),
+                    QuadFelt::from(Felt::new(2)),
+                    QuadFelt::from(Felt::new(42))
+                )]
+            );
+        }
+        err => panic!("unexpected violation: {err}"),
+    }
+}
+
+#[test]
+fn stack_transition_violation_is_reported() {
+    let (mut trace, stack_inputs) = build_trace(&[1, 255]);
+
+    // corrupt the result of the U32add operation which is executed at row 3
+    let row = 4;
+    let value = trace.main_segment().get(STACK_TRACE_OFFSET, row);
+    set_cell(&mut trace, STACK_TRACE_OFFSET, row, value + ONE);
+
+    let err = trace.check_constraints(stack_inputs, &rand_elements()).unwrap_err();
+    match err {
+        ConstraintViolation::Transition {
+            name, row, columns, ..
+        } => {
+            assert!(name.starts_with("stack."), "unexpected constraint {name}");
+            assert_eq!(row, 3);
+            assert!(columns.iter().any(|(column, ..)| column == "s0"));
+        }
+        err => panic!("unexpected violation: {err}"),
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Executes a program which compiles to PAD DROP U32ADD operations against the specified stack.
+fn build_trace(stack: &[u64]) -> (ExecutionTrace, StackInputs) {
+    let program = Assembler::default()
+        .compile("begin push.0 drop u32overflowing_add end")
+        .unwrap();
+    let stack_inputs = StackInputs::try_from_values(stack.iter().copied()).unwrap();
+    let host = DefaultHost::default();
+    let trace =
+        crate::execute(&program, stack_inputs.clone(), host, ExecutionOptions::default()).unwrap();
+    (trace, stack_inputs)
+}
+
+fn rand_elements() -> [QuadFelt; AUX_TRACE_RAND_ELEMENTS] {
+    rand_array()
+}
+
+fn set_cell(trace: &mut ExecutionTrace, column: usize, row: usize, value: Felt) {
+    trace.main_trace.get_column_mut(column)[row] = value;
+}
@@ -8,10 +8,12 @@ use vm_core::{
     code_blocks::CodeBlock, CodeBlockTable, Kernel, Operation, StackOutputs, Word, ONE, ZERO,
 };
 
+mod checker;
 mod chiplets;
 mod hasher;
 mod range;
 mod stack;
+mod table;
 
 // TEST HELPERS
 // ================================================================================================
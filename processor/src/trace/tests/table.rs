@@ -0,0 +1,117 @@
+use super::{build_trace_from_ops, Felt, Trace, ONE, ZERO};
+use crate::{QuadFelt, TraceTable};
+use miden_air::trace::{
+    main_trace_column_names, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH,
+    RANGE_CHECK_AUX_TRACE_OFFSET, STACK_TRACE_OFFSET, TRACE_WIDTH,
+};
+use test_utils::rand::rand_array;
+use vm_core::{
+    utils::{Deserializable, Serializable},
+    Operation,
+};
+
+// TRACE EXPORT
+// ================================================================================================
+
+#[test]
+fn main_trace_table_matches_trace() {
+    let trace = build_trace_from_ops(vec![Operation::Add, Operation::Pad], &[1, 2]);
+    let table = trace.main_trace_table();
+
+    assert_eq!(table.num_columns(), TRACE_WIDTH);
+    assert_eq!(table.num_rows(), trace.length());
+    assert_eq!(table.column_names(), main_trace_column_names().as_slice());
+    for i in 0..TRACE_WIDTH {
+        assert_eq!(table.get_column(i), trace.main_segment().get_column(i));
+    }
+
+    // columns can be looked up by name
+    assert_eq!(table.get_column_by_name("clk").unwrap()[5], Felt::new(5));
+    assert_eq!(table.get_column_by_name("s0").unwrap(), table.get_column(STACK_TRACE_OFFSET));
+    assert!(table.get_column_by_name("no_such_column").is_none());
+}
+
+#[test]
+fn trace_table_with_aux_splits_extension_columns() {
+    let mut trace = build_trace_from_ops(vec![Operation::U32add], &[1, 255]);
+    let rand_elements = rand_array::<QuadFelt, AUX_TRACE_RAND_ELEMENTS>();
+    let table = trace.trace_table_with_aux(&rand_elements);
+
+    assert_eq!(table.num_columns(), TRACE_WIDTH + 2 * AUX_TRACE_WIDTH);
+    assert_eq!(table.num_rows(), trace.length());
+
+    let aux_trace = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let range_b = aux_trace.get_column(RANGE_CHECK_AUX_TRACE_OFFSET);
+    let range_b_0 = table.get_column_by_name("range_b.0").unwrap();
+    let range_b_1 = table.get_column_by_name("range_b.1").unwrap();
+    for (i, value) in range_b.iter().enumerate() {
+        assert_eq!(value.to_base_elements(), [range_b_0[i], range_b_1[i]]);
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+#[test]
+fn trace_table_serialization() {
+    let trace = build_trace_from_ops(vec![Operation::Add, Operation::Pad], &[1, 2]);
+    let table = trace.main_trace_table();
+
+    let bytes = table.to_bytes();
+    assert_eq!(TraceTable::read_from_bytes(&bytes).unwrap(), table);
+
+    // unknown versions and truncated data are rejected
+    let mut invalid = bytes.clone();
+    invalid[0] = 0;
+    assert!(TraceTable::read_from_bytes(&invalid).is_err());
+    assert!(TraceTable::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // headers claiming more data than is present are rejected before the memory is allocated
+    let mut oversized = vec![1];
+    oversized.extend_from_slice(&u32::MAX.to_le_bytes());
+    oversized.extend_from_slice(&u64::MAX.to_le_bytes());
+    assert!(TraceTable::read_from_bytes(&oversized).is_err());
+
+    let mut oversized = bytes[..1].to_vec();
+    oversized.extend_from_slice(&1_u32.to_le_bytes());
+    oversized.extend_from_slice(&u64::MAX.to_le_bytes());
+    oversized.extend_from_slice(&3_u32.to_le_bytes());
+    oversized.extend_from_slice(b"clk");
+    assert!(TraceTable::read_from_bytes(&oversized).is_err());
+}
+
+#[test]
+fn trace_table_csv() {
+    let trace = build_trace_from_ops(vec![Operation::Add, Operation::Pad], &[1, 2]);
+    let table = trace.main_trace_table();
+
+    let mut csv = Vec::new();
+    table.write_csv(&mut csv).unwrap();
+    let header = csv.split(|&b| b == b'\n').next().unwrap();
+    assert!(header.starts_with(b"clk,fmp,ctx,in_syscall,fn_hash[0]"));
+    assert_eq!(TraceTable::read_csv(csv.as_slice()).unwrap(), table);
+
+    // rows with a wrong number of values and non-canonical field elements are rejected
+    assert!(TraceTable::read_csv(&b"a,b\n1,2\n3\n"[..]).is_err());
+    assert!(TraceTable::read_csv(&b"a,b\n1,18446744073709551615\n"[..]).is_err());
+}
+
+// TRACE DIFFS
+// ================================================================================================
+
+#[test]
+fn trace_table_first_difference() {
+    let names = vec!["a".to_string(), "b".to_string()];
+    let table = TraceTable::new(names.clone(), vec![vec![ZERO, ONE], vec![ONE, ONE]]);
+    assert_eq!(table.first_difference(&table), None);
+
+    let other = TraceTable::new(names.clone(), vec![vec![ZERO, ONE], vec![ONE, ZERO]]);
+    assert_eq!(table.first_difference(&other), Some((1, "b")));
+
+    let shorter = TraceTable::new(names, vec![vec![ZERO], vec![ONE]]);
+    assert_eq!(table.first_difference(&shorter), Some((1, "a")));
+
+    let renamed =
+        TraceTable::new(vec!["a".to_string(), "c".to_string()], vec![vec![ZERO], vec![ONE]]);
+    assert_eq!(table.first_difference(&renamed), Some((0, "b")));
+}
@@ -0,0 +1,114 @@
+use assembly::Assembler;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use miden_prover::{prove, Program, ProvingOptions, StackInputs};
+use processor::DefaultHost;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Base-2 logarithms of the execution trace lengths for which proof generation is benchmarked.
+const TRACE_LENGTHS: [u32; 2] = [16, 18];
+
+// PEAK MEMORY TRACKING
+// ================================================================================================
+
+/// A global allocator which keeps track of the number of bytes currently allocated on the heap
+/// and of the largest number of bytes allocated at any point since the last reset.
+struct PeakAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl PeakAllocator {
+    /// Resets the peak to the current number of allocated bytes and returns this number.
+    fn reset_peak(&self) -> usize {
+        let current = self.current.load(Ordering::SeqCst);
+        self.peak.store(current, Ordering::SeqCst);
+        current
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = self.current.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            self.peak.fetch_max(current, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator {
+    current: AtomicUsize::new(0),
+    peak: AtomicUsize::new(0),
+};
+
+// BENCHMARKS
+// ================================================================================================
+
+/// Benchmarks proof generation by the standard and by the low-memory provers, and prints the peak
+/// heap usage of each prover before the measurements start.
+fn low_memory_proving(c: &mut Criterion) {
+    let mut group = c.benchmark_group("low_memory_proving");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(30));
+
+    for log_trace_length in TRACE_LENGTHS {
+        let program = build_program(log_trace_length);
+        let presets = [
+            ("standard", ProvingOptions::default()),
+            ("low_memory", ProvingOptions::default().with_low_memory_proving()),
+        ];
+
+        for (name, options) in presets {
+            let baseline = ALLOCATOR.reset_peak();
+            prove_program(&program, options.clone());
+            let peak_usage = ALLOCATOR.peak() - baseline;
+            println!(
+                "{name} prover, trace length 2^{log_trace_length}: peak heap usage {} MB",
+                peak_usage / (1024 * 1024)
+            );
+
+            let id = BenchmarkId::new(name, format!("2^{log_trace_length}"));
+            group.bench_with_input(id, &options, |bench, options| {
+                bench.iter(|| prove_program(&program, options.clone()));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(low_memory_group, low_memory_proving);
+criterion_main!(low_memory_group);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a program which computes Fibonacci numbers, and the execution trace of which has
+/// 2^`log_trace_length` rows.
+fn build_program(log_trace_length: u32) -> Program {
+    // each iteration of the loop takes 3 cycles; this leaves enough room for the cycles spent
+    // outside of the loop to keep the trace length below the next power of two
+    let num_iterations = (1 << log_trace_length) / 4;
+    let source = format!("begin repeat.{num_iterations} swap dup.1 add end end");
+    Assembler::default().compile(source).expect("failed to compile the program")
+}
+
+fn prove_program(program: &Program, options: ProvingOptions) {
+    let stack_inputs = StackInputs::try_from_values([0, 1]).unwrap();
+    prove(program, stack_inputs, DefaultHost::default(), options).expect("failed to prove");
+}
@@ -1,5 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
 use core::marker::PhantomData;
 use processor::{
@@ -21,16 +25,29 @@ use winter_prover::Trace;
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+mod low_memory;
+
+#[cfg(feature = "std")]
+mod observer;
+#[cfg(feature = "std")]
+use observer::PhaseTracker;
+
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofBundle,
+    ProvingOptions, ProvingOptionsError,
+};
 pub use processor::{
-    crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
-    Program, StackInputs, StackOutputs, Word,
+    crypto, math, utils, AdviceInputs, AdviceProvider, Digest, ExecutionError, Host, InputError,
+    MemAdviceProvider, Program, StackInputs, StackOutputs, Word,
 };
 pub use winter_prover::StarkProof;
 
+#[cfg(feature = "std")]
+pub use observer::{CancellationToken, ProvingObserver, ProvingPhase};
+
 // PROVER
 // ================================================================================================
 
@@ -43,6 +60,10 @@ pub use winter_prover::StarkProof;
 ///
 /// # Errors
 /// Returns an error if program execution or STARK proof generation fails for any reason.
+///
+/// When the `metal` feature is enabled, returns an error if low-memory proof generation is
+/// requested for proofs based on the RPO hash function, since the low-memory prover cannot use
+/// GPU acceleration.
 pub fn prove<H>(
     program: &Program,
     stack_inputs: StackInputs,
@@ -52,11 +73,22 @@ pub fn prove<H>(
 where
     H: Host,
 {
+    #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
+    if options.is_low_memory() && options.hash_fn() == HashFunction::Rpo256 {
+        return Err(ExecutionError::UnsupportedProvingOptions(
+            "low-memory proof generation cannot be combined with GPU acceleration",
+        ));
+    }
+
     // execute the program to create an execution trace
     #[cfg(feature = "std")]
     let now = Instant::now();
-    let trace =
-        processor::execute(program, stack_inputs.clone(), host, *options.execution_options())?;
+    let trace = processor::execute(
+        program,
+        stack_inputs.clone(),
+        host,
+        options.execution_options().clone(),
+    )?;
     #[cfg(feature = "std")]
     let padding_percentage = (trace.trace_len_summary().padded_trace_len()
         - trace.trace_len_summary().trace_len())
@@ -73,21 +105,27 @@ where
 
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
+    let deterministic = options.is_deterministic();
+    let low_memory = options.is_low_memory();
 
     // generate STARK proof
     let proof = match hash_fn {
-        HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
-            options,
-            stack_inputs,
-            stack_outputs.clone(),
-        )
-        .prove(trace),
-        HashFunction::Blake3_256 => ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
-            options,
-            stack_inputs,
-            stack_outputs.clone(),
-        )
-        .prove(trace),
+        HashFunction::Blake3_192 => {
+            let prover = ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            prove_trace(prover, trace, deterministic, low_memory, |_| Ok(()))
+        }
+        HashFunction::Blake3_256 => {
+            let prover = ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            prove_trace(prover, trace, deterministic, low_memory, |_| Ok(()))
+        }
         HashFunction::Rpo256 => {
             let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
                 options,
@@ -96,15 +134,150 @@ where
             );
             #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
             let prover = gpu::GpuRpoExecutionProver(prover);
-            prover.prove(trace)
+            prove_trace(prover, trace, deterministic, low_memory, |_| Ok(()))
         }
-    }
-    .map_err(ExecutionError::ProverError)?;
+    }?;
+    let proof = ExecutionProof::new(proof, hash_fn);
+
+    Ok((stack_outputs, proof))
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], while reporting the
+/// progress of proof generation to the specified `observer`.
+///
+/// The observer is notified when each [ProvingPhase] starts and finishes. Between the phases, the
+/// `cancellation` token is checked, and if cancellation has been requested, proof generation is
+/// aborted before the next phase starts. To abort a long-running program execution, the host can
+/// signal cancellation via [Host::is_cancelled()].
+///
+/// The STARK prover used by [prove()] does not expose the boundaries between its phases; so, the
+/// proof is always generated by the low-memory prover (see
+/// [ProvingOptions::with_low_memory_proving()]), regardless of the specified options. The proof
+/// is identical to the proof generated by [prove()], but proof generation takes longer and does not
+/// use GPU acceleration enabled by the `metal` feature.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason, or
+/// [ExecutionError::ProvingCancelled] if proof generation has been cancelled.
+#[cfg(feature = "std")]
+pub fn prove_with_observer<H, O>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    observer: &mut O,
+    cancellation: &CancellationToken,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    H: Host,
+    O: ProvingObserver + Send,
+{
+    let tracker = PhaseTracker::new(observer, cancellation);
+
+    // execute the program to create an execution trace
+    tracker.advance(ProvingPhase::Execution)?;
+    let trace = processor::execute(
+        program,
+        stack_inputs.clone(),
+        host,
+        options.execution_options().clone(),
+    )?;
+
+    let stack_outputs = trace.stack_outputs().clone();
+    let hash_fn = options.hash_fn();
+    let deterministic = options.is_deterministic();
+
+    // generate STARK proof
+    let proof = match hash_fn {
+        HashFunction::Blake3_192 => {
+            let prover = ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            tracker.prove(prover, trace, deterministic)
+        }
+        HashFunction::Blake3_256 => {
+            let prover = ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            tracker.prove(prover, trace, deterministic)
+        }
+        HashFunction::Rpo256 => {
+            let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            tracker.prove(prover, trace, deterministic)
+        }
+    }?;
     let proof = ExecutionProof::new(proof, hash_fn);
 
     Ok((stack_outputs, proof))
 }
 
+/// Returns the data committed to by a program as its public outputs, or None if the advice map
+/// of the specified advice provider does not contain data for the commitment.
+///
+/// Programs can commit to the contents of a memory region using `std::sys::commit_outputs`
+/// procedure, which leaves the commitment at the top of the stack and copies the data into the
+/// advice map under the commitment. To retrieve the data, the host should be passed to [prove()]
+/// by mutable reference; once proving completes, this function can be called with the stack
+/// outputs returned by [prove()] and the advice provider of the host. The verifier can check the
+/// data against the stack outputs using `verify_output_data()`.
+pub fn get_output_data<'a, A: AdviceProvider>(
+    stack_outputs: &StackOutputs,
+    advice_provider: &'a A,
+) -> Option<&'a [Felt]> {
+    let commitment = Digest::from(stack_outputs.get_stack_word(0)?);
+    advice_provider.get_mapped_values(&commitment.as_bytes())
+}
+
+/// Generates a STARK proof for the specified execution trace using the specified prover.
+///
+/// If `deterministic` is set and the `concurrent` feature is enabled, the prover runs in a
+/// single-threaded thread pool. This makes the search for the proof-of-work nonce, which is
+/// otherwise performed in parallel, sequential; all other steps of proof generation produce the
+/// same results regardless of the number of threads.
+///
+/// If `low_memory` is set, the proof is generated by the low-memory prover, which notifies
+/// `on_checkpoint` upon completion of each of its checkpoints and stops if `on_checkpoint` returns
+/// an error; otherwise, `on_checkpoint` is not invoked.
+#[cfg_attr(not(feature = "concurrent"), allow(unused_variables))]
+fn prove_trace<P, C>(
+    prover: P,
+    trace: ExecutionTrace,
+    deterministic: bool,
+    low_memory: bool,
+    on_checkpoint: C,
+) -> Result<StarkProof, ExecutionError>
+where
+    P: Prover<BaseField = Felt, Trace = ExecutionTrace> + Send,
+    C: FnMut(low_memory::Checkpoint) -> Result<(), ExecutionError> + Send,
+{
+    let prove = move || {
+        if low_memory {
+            low_memory::prove(&prover, trace, on_checkpoint)
+        } else {
+            prover.prove(trace).map_err(ExecutionError::ProverError)
+        }
+    };
+
+    #[cfg(feature = "concurrent")]
+    if deterministic {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .expect("failed to build a single-threaded thread pool");
+        return pool.install(prove);
+    }
+
+    prove()
+}
+
 // PROVER
 // ================================================================================================
 
@@ -0,0 +1,196 @@
+use core::marker::PhantomData;
+use processor::utils::collections::Vec;
+use winter_air::{
+    proof::{Commitments, Context, OodFrame, Queries, StarkProof},
+    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
+};
+use winter_fri::{self as fri, FriProof};
+use winter_prover::{
+    crypto::{ElementHasher, RandomCoin},
+    math::{FieldElement, ToElements},
+};
+
+#[cfg(feature = "concurrent")]
+use winter_prover::iterators::*;
+
+// PROVER CHANNEL
+// ================================================================================================
+
+/// Simulates the interaction between the prover and the verifier.
+///
+/// The channel keeps track of the commitments sent by the prover, and draws the randomness which
+/// would be sent by the verifier from a public coin seeded with these commitments. It follows
+/// the same protocol as the channel of the STARK prover, and thus, the verifier sees no
+/// difference between the proofs generated by the two provers.
+pub struct ProverChannel<'a, A, E, H, R>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+{
+    air: &'a A,
+    public_coin: R,
+    context: Context,
+    commitments: Commitments,
+    ood_frame: OodFrame,
+    pow_nonce: u64,
+    _field_element: PhantomData<E>,
+}
+
+impl<'a, A, E, H, R> ProverChannel<'a, A, E, H, R>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+{
+    /// Returns a new channel for the computation described by the specified AIR; the public coin
+    /// is seeded with the proof context and the public inputs of the computation.
+    pub fn new(air: &'a A, mut pub_inputs_elements: Vec<A::BaseField>) -> Self {
+        let context = Context::new::<A::BaseField>(air.trace_info(), air.options().clone());
+
+        let mut coin_seed_elements = context.to_elements();
+        coin_seed_elements.append(&mut pub_inputs_elements);
+
+        Self {
+            air,
+            public_coin: RandomCoin::new(&coin_seed_elements),
+            context,
+            commitments: Commitments::default(),
+            ood_frame: OodFrame::default(),
+            pow_nonce: 0,
+            _field_element: PhantomData,
+        }
+    }
+
+    // COMMITMENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Commits to the LDE of a trace segment.
+    pub fn commit_trace(&mut self, trace_root: H::Digest) {
+        self.commitments.add::<H>(&trace_root);
+        self.public_coin.reseed(trace_root);
+    }
+
+    /// Commits to the evaluations of the constraint composition polynomial columns.
+    pub fn commit_constraints(&mut self, constraint_root: H::Digest) {
+        self.commitments.add::<H>(&constraint_root);
+        self.public_coin.reseed(constraint_root);
+    }
+
+    /// Sends the evaluations of the trace polynomials at the out-of-domain point to the verifier.
+    pub fn send_ood_trace_states(&mut self, trace_states: &[Vec<E>]) {
+        let result = self.ood_frame.set_trace_states(trace_states);
+        self.public_coin.reseed(H::hash_elements(&result));
+    }
+
+    /// Sends the evaluations of the constraint composition polynomial columns at the
+    /// out-of-domain point to the verifier.
+    pub fn send_ood_constraint_evaluations(&mut self, evaluations: &[E]) {
+        self.ood_frame.set_constraint_evaluations(evaluations);
+        self.public_coin.reseed(H::hash_elements(evaluations));
+    }
+
+    // PUBLIC COIN METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the random elements needed to build the specified auxiliary trace segment.
+    pub fn get_aux_trace_segment_rand_elements(&mut self, aux_segment_idx: usize) -> Vec<E> {
+        self.air
+            .get_aux_trace_segment_random_elements(aux_segment_idx, &mut self.public_coin)
+            .expect("failed to draw random elements for an auxiliary trace segment")
+    }
+
+    /// Returns the coefficients for the random linear combination of the constraints.
+    pub fn get_constraint_composition_coeffs(&mut self) -> ConstraintCompositionCoefficients<E> {
+        self.air
+            .get_constraint_composition_coefficients(&mut self.public_coin)
+            .expect("failed to draw composition coefficients")
+    }
+
+    /// Returns the out-of-domain point at which the trace and constraint composition polynomials
+    /// are evaluated.
+    pub fn get_ood_point(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw OOD point")
+    }
+
+    /// Returns the coefficients for the construction of the DEEP composition polynomial.
+    pub fn get_deep_composition_coeffs(&mut self) -> DeepCompositionCoefficients<E> {
+        self.air
+            .get_deep_composition_coefficients(&mut self.public_coin)
+            .expect("failed to draw DEEP composition coefficients")
+    }
+
+    /// Returns the positions of the LDE domain at which the commitments are queried.
+    pub fn get_query_positions(&mut self) -> Vec<usize> {
+        let num_queries = self.context.options().num_queries();
+        let lde_domain_size = self.context.lde_domain_size();
+        self.public_coin
+            .draw_integers(num_queries, lde_domain_size)
+            .expect("failed to draw query position")
+    }
+
+    /// Finds a proof-of-work nonce for the query seed and reseeds the public coin with it.
+    pub fn grind_query_seed(&mut self) {
+        let grinding_factor = self.context.options().grinding_factor();
+
+        #[cfg(not(feature = "concurrent"))]
+        let nonce = (1..u64::MAX)
+            .find(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
+            .expect("nonce not found");
+
+        #[cfg(feature = "concurrent")]
+        let nonce = (1..u64::MAX)
+            .into_par_iter()
+            .find_any(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
+            .expect("nonce not found");
+
+        self.pow_nonce = nonce;
+        self.public_coin.reseed_with_int(nonce);
+    }
+
+    // PROOF BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a proof from the commitments and the OOD frame sent through this channel, and from
+    /// the specified query results.
+    pub fn build_proof(
+        self,
+        trace_queries: Vec<Queries>,
+        constraint_queries: Queries,
+        fri_proof: FriProof,
+    ) -> StarkProof {
+        StarkProof {
+            context: self.context,
+            commitments: self.commitments,
+            ood_frame: self.ood_frame,
+            trace_queries,
+            constraint_queries,
+            fri_proof,
+            pow_nonce: self.pow_nonce,
+        }
+    }
+}
+
+// FRI PROVER CHANNEL IMPLEMENTATION
+// ================================================================================================
+
+impl<'a, A, E, H, R> fri::ProverChannel<E> for ProverChannel<'a, A, E, H, R>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+{
+    type Hasher = H;
+
+    fn commit_fri_layer(&mut self, layer_root: H::Digest) {
+        self.commitments.add::<H>(&layer_root);
+        self.public_coin.reseed(layer_root);
+    }
+
+    fn draw_fri_alpha(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw FRI alpha")
+    }
+}
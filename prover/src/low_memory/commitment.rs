@@ -0,0 +1,136 @@
+use super::CosetDomain;
+use processor::{
+    math::{Felt, FieldElement},
+    utils::collections::Vec,
+};
+use winter_air::proof::Queries;
+use winter_prover::{
+    crypto::{ElementHasher, MerkleTree},
+    ColMatrix,
+};
+
+#[cfg(feature = "concurrent")]
+use winter_prover::iterators::*;
+
+// SEGMENT COMMITMENT
+// ================================================================================================
+
+/// Builds a commitment to the evaluations of the specified polynomials over the LDE domain and
+/// returns the root of the commitment Merkle tree.
+///
+/// The commitment is the same as the one built by the STARK prover from the extended segment:
+/// each row of the extension is hashed into a leaf of the Merkle tree. However, the extension is
+/// computed one coset of the trace domain at a time, and only the evaluations over a single coset
+/// are kept in memory.
+pub fn commit_to_segment<E, H>(polys: &ColMatrix<E>, domain: &CosetDomain) -> H::Digest
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let (tree, _) = build_segment_tree::<E, H>(polys, domain, &[]);
+    *tree.root()
+}
+
+/// Queries the commitment to the evaluations of the specified polynomials over the LDE domain at
+/// the specified positions.
+///
+/// Since the commitment is not retained after its root has been sent to the verifier, the
+/// extension and the Merkle tree are recomputed; the rows at the queried positions are read while
+/// the extension is being recomputed.
+pub fn query_segment<E, H>(
+    polys: &ColMatrix<E>,
+    domain: &CosetDomain,
+    positions: &[usize],
+) -> Queries
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let (tree, rows) = build_segment_tree::<E, H>(polys, domain, positions);
+    let merkle_proof = tree
+        .prove_batch(positions)
+        .expect("failed to generate a Merkle proof for segment queries");
+    Queries::new(merkle_proof, rows)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Evaluates the specified polynomials over the LDE domain one coset of the trace domain at a
+/// time, and builds a Merkle tree from the hashes of the rows of the extension.
+///
+/// Also returns the rows of the extension at the specified positions.
+fn build_segment_tree<E, H>(
+    polys: &ColMatrix<E>,
+    domain: &CosetDomain,
+    positions: &[usize],
+) -> (MerkleTree<H>, Vec<Vec<E>>)
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let blowup = domain.lde_blowup();
+    let mut row_hashes = vec![H::Digest::default(); domain.trace_length() * blowup];
+    let mut rows = vec![Vec::new(); positions.len()];
+
+    for coset_idx in 0..blowup {
+        let coset_offset = domain.coset_offset(coset_idx, blowup);
+        let columns = domain.evaluate_over_coset(polys, coset_offset);
+
+        // the k-th row of the coset is at position coset_idx + k * blowup of the LDE domain
+        let coset_hashes = hash_rows::<E, H>(&columns);
+        for (k, hash) in coset_hashes.into_iter().enumerate() {
+            row_hashes[coset_idx + k * blowup] = hash;
+        }
+
+        for (&position, row) in positions.iter().zip(rows.iter_mut()) {
+            if position % blowup == coset_idx {
+                *row = columns.iter().map(|column| column[position / blowup]).collect();
+            }
+        }
+    }
+
+    let tree = MerkleTree::new(row_hashes).expect("failed to construct segment Merkle tree");
+    (tree, rows)
+}
+
+/// Returns the hashes of the rows of the matrix with the specified columns.
+#[cfg(not(feature = "concurrent"))]
+fn hash_rows<E, H>(columns: &[Vec<E>]) -> Vec<H::Digest>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let mut row = vec![E::ZERO; columns.len()];
+    (0..columns[0].len())
+        .map(|row_idx| {
+            read_row_into(columns, row_idx, &mut row);
+            H::hash_elements(&row)
+        })
+        .collect()
+}
+
+/// Returns the hashes of the rows of the matrix with the specified columns.
+#[cfg(feature = "concurrent")]
+fn hash_rows<E, H>(columns: &[Vec<E>]) -> Vec<H::Digest>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    (0..columns[0].len())
+        .into_par_iter()
+        .map_init(
+            || vec![E::ZERO; columns.len()],
+            |row, row_idx| {
+                read_row_into(columns, row_idx, row);
+                H::hash_elements(row)
+            },
+        )
+        .collect()
+}
+
+fn read_row_into<E: FieldElement>(columns: &[Vec<E>], row_idx: usize, row: &mut [E]) {
+    for (value, column) in row.iter_mut().zip(columns) {
+        *value = column[row_idx];
+    }
+}
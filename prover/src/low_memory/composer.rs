@@ -0,0 +1,106 @@
+use super::CosetDomain;
+use processor::{
+    math::{Felt, FieldElement},
+    utils::collections::Vec,
+};
+use winter_prover::{
+    math::{add_in_place, mul_acc, polynom},
+    ColMatrix, CompositionPoly, DeepCompositionCoefficients,
+};
+
+// OUT-OF-DOMAIN FRAME
+// ================================================================================================
+
+/// Returns the evaluations of the main and the auxiliary trace polynomials at `z` and at `z * g`,
+/// where `g` is the generator of the trace domain.
+pub fn get_ood_frame<E>(
+    main_polys: &ColMatrix<Felt>,
+    aux_polys: &[ColMatrix<E>],
+    z: E,
+    domain: &CosetDomain,
+) -> Vec<Vec<E>>
+where
+    E: FieldElement<BaseField = Felt>,
+{
+    let next_z = z * E::from(domain.trace_generator());
+    [z, next_z]
+        .into_iter()
+        .map(|x| {
+            let main = main_polys.columns().map(|poly| polynom::eval(poly, x));
+            let aux =
+                aux_polys.iter().flat_map(|polys| polys.columns()).map(|p| polynom::eval(p, x));
+            main.chain(aux).collect()
+        })
+        .collect()
+}
+
+// DEEP COMPOSITION POLYNOMIAL
+// ================================================================================================
+
+/// Builds the DEEP composition polynomial in coefficient form.
+///
+/// The polynomial is a random linear combination of quotients `(T(x) - T(z)) / (x - z)` and
+/// `(T(x) - T(z * g)) / (x - z * g)` for all trace polynomials `T`, and `(H(x) - H(z)) / (x - z)`
+/// for all constraint composition polynomial columns `H`; it is computed in the same way as by
+/// the STARK prover.
+#[allow(clippy::too_many_arguments)]
+pub fn build_deep_composition_poly<E>(
+    main_polys: &ColMatrix<Felt>,
+    aux_polys: &[ColMatrix<E>],
+    composition_poly: &CompositionPoly<E>,
+    ood_trace_states: &[Vec<E>],
+    ood_evaluations: &[E],
+    z: E,
+    coefficients: &DeepCompositionCoefficients<E>,
+    domain: &CosetDomain,
+) -> Vec<E>
+where
+    E: FieldElement<BaseField = Felt>,
+{
+    let trace_length = domain.trace_length();
+    let next_z = z * E::from(domain.trace_generator());
+
+    // combine all trace polynomials into two polynomials, subtracting their values at z and at
+    // z * g respectively
+    let mut t1_composition = E::zeroed_vector(trace_length);
+    let mut t2_composition = E::zeroed_vector(trace_length);
+    let mut i = 0;
+    for poly in main_polys.columns() {
+        let k = coefficients.trace[i];
+        mul_acc::<Felt, E>(&mut t1_composition, poly, k);
+        t1_composition[0] -= ood_trace_states[0][i] * k;
+        mul_acc::<Felt, E>(&mut t2_composition, poly, k);
+        t2_composition[0] -= ood_trace_states[1][i] * k;
+        i += 1;
+    }
+    for poly in aux_polys.iter().flat_map(|polys| polys.columns()) {
+        let k = coefficients.trace[i];
+        mul_acc::<E, E>(&mut t1_composition, poly, k);
+        t1_composition[0] -= ood_trace_states[0][i] * k;
+        mul_acc::<E, E>(&mut t2_composition, poly, k);
+        t2_composition[0] -= ood_trace_states[1][i] * k;
+        i += 1;
+    }
+
+    // divide the combinations by (x - z) and (x - z * g) respectively, and merge them
+    polynom::syn_div_in_place(&mut t1_composition, 1, z);
+    polynom::syn_div_in_place(&mut t2_composition, 1, next_z);
+    add_in_place(&mut t1_composition, &t2_composition);
+    let mut result = t1_composition;
+
+    // add the quotients of the constraint composition polynomial columns; the columns are kept
+    // intact since they are needed again in the query phase
+    for ((poly, &value_at_z), &k) in composition_poly
+        .data()
+        .columns()
+        .zip(ood_evaluations)
+        .zip(coefficients.constraints.iter())
+    {
+        let mut quotient = poly.to_vec();
+        quotient[0] -= value_at_z;
+        polynom::syn_div_in_place(&mut quotient, 1, z);
+        mul_acc::<E, E>(&mut result, &quotient, k);
+    }
+
+    result
+}
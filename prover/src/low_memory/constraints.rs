@@ -0,0 +1,350 @@
+use super::CosetDomain;
+use processor::{
+    math::{Felt, FieldElement, StarkField},
+    utils::collections::Vec,
+};
+use winter_air::{
+    Air, AuxTraceRandElements, BoundaryConstraints, ConstraintCompositionCoefficients,
+    ConstraintDivisor, EvaluationFrame,
+};
+use winter_prover::{
+    math::{batch_inversion, polynom},
+    ColMatrix,
+};
+
+#[cfg(feature = "concurrent")]
+use winter_prover::iterators::*;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum number of rows of a coset evaluated by a single thread.
+#[cfg(feature = "concurrent")]
+const MIN_FRAGMENT_SIZE: usize = 1024;
+
+// CONSTRAINT EVALUATOR
+// ================================================================================================
+
+/// Evaluates the constraints of a computation over the constraint evaluation domain, one coset of
+/// the trace domain at a time.
+///
+/// Within a coset, the point following `x` in the trace domain, i.e., `x * g`, is the next point
+/// of the same coset. Thus, the evaluations of the trace polynomials over a single coset contain
+/// both rows of every evaluation frame.
+pub struct ConstraintEvaluator<'a, A: Air<BaseField = Felt>, E: FieldElement<BaseField = Felt>> {
+    air: &'a A,
+    main_coefficients: Vec<E>,
+    aux_coefficients: Vec<E>,
+    transition_divisor: ConstraintDivisor<Felt>,
+    boundary_constraints: BoundaryConstraints<E>,
+    boundary_divisors: Vec<ConstraintDivisor<Felt>>,
+    aux_rand_elements: AuxTraceRandElements<E>,
+    periodic_column_polys: Vec<Vec<Felt>>,
+}
+
+impl<'a, A: Air<BaseField = Felt>, E: FieldElement<BaseField = Felt>>
+    ConstraintEvaluator<'a, A, E>
+{
+    /// Returns a new evaluator of the constraints of the specified AIR.
+    pub fn new(
+        air: &'a A,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self {
+        let transition_constraints =
+            air.get_transition_constraints(&composition_coefficients.transition);
+        let boundary_constraints =
+            air.get_boundary_constraints(&aux_rand_elements, &composition_coefficients.boundary);
+
+        // boundary constraint groups of the main and the auxiliary segments may share divisors;
+        // the evaluations of such groups are divided by their divisor only once
+        let mut boundary_divisors: Vec<ConstraintDivisor<Felt>> = Vec::new();
+        let main_divisors = boundary_constraints.main_constraints().iter().map(|g| g.divisor());
+        let aux_divisors = boundary_constraints.aux_constraints().iter().map(|g| g.divisor());
+        for divisor in main_divisors.chain(aux_divisors) {
+            if !boundary_divisors.contains(divisor) {
+                boundary_divisors.push(divisor.clone());
+            }
+        }
+
+        Self {
+            air,
+            main_coefficients: transition_constraints.main_constraint_coef(),
+            aux_coefficients: transition_constraints.aux_constraint_coef(),
+            transition_divisor: transition_constraints.divisor().clone(),
+            boundary_constraints,
+            boundary_divisors,
+            aux_rand_elements,
+            periodic_column_polys: air.get_periodic_column_polys(),
+        }
+    }
+
+    /// Evaluates the random linear combination of the constraints, divided by their divisors,
+    /// over the constraint evaluation domain, and returns the evaluations in the order of the
+    /// points of the domain.
+    ///
+    /// The constraints are evaluated against the trace polynomials of the main segment and of
+    /// the auxiliary segments.
+    pub fn evaluate(
+        &self,
+        main_polys: &ColMatrix<Felt>,
+        aux_polys: &[ColMatrix<E>],
+        domain: &CosetDomain,
+    ) -> Vec<E> {
+        let trace_length = domain.trace_length();
+        let blowup = domain.ce_blowup();
+        let mut result = vec![E::ZERO; trace_length * blowup];
+
+        for coset_idx in 0..blowup {
+            let coset_offset = domain.coset_offset(coset_idx, blowup);
+            let coset = CosetEvaluations {
+                offset: coset_offset,
+                main: domain.evaluate_over_coset(main_polys, coset_offset),
+                aux: aux_polys
+                    .iter()
+                    .flat_map(|polys| domain.evaluate_over_coset(polys, coset_offset))
+                    .collect(),
+                periodic: self
+                    .periodic_column_polys
+                    .iter()
+                    .map(|poly| evaluate_periodic_column(poly, domain, coset_offset))
+                    .collect(),
+                inv_transition_divisor: evaluate_inv_divisor(
+                    &self.transition_divisor,
+                    domain,
+                    coset_offset,
+                ),
+                inv_boundary_divisors: self
+                    .boundary_divisors
+                    .iter()
+                    .map(|divisor| evaluate_inv_divisor(divisor, domain, coset_offset))
+                    .collect(),
+            };
+
+            // the k-th point of the coset is at position coset_idx + k * blowup of the domain
+            let evaluations = self.evaluate_coset(&coset, domain);
+            for (k, evaluation) in evaluations.into_iter().enumerate() {
+                result[coset_idx + k * blowup] = evaluation;
+            }
+        }
+
+        result
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates the constraints over all points of the specified coset.
+    #[cfg(not(feature = "concurrent"))]
+    fn evaluate_coset(&self, coset: &CosetEvaluations<E>, domain: &CosetDomain) -> Vec<E> {
+        let mut result = vec![E::ZERO; domain.trace_length()];
+        self.evaluate_fragment(coset, domain, 0, &mut result);
+        result
+    }
+
+    /// Evaluates the constraints over all points of the specified coset.
+    #[cfg(feature = "concurrent")]
+    fn evaluate_coset(&self, coset: &CosetEvaluations<E>, domain: &CosetDomain) -> Vec<E> {
+        let mut result = vec![E::ZERO; domain.trace_length()];
+        let fragment_size = core::cmp::max(
+            domain.trace_length() / rayon::current_num_threads().next_power_of_two(),
+            MIN_FRAGMENT_SIZE,
+        );
+        result.par_chunks_mut(fragment_size).enumerate().for_each(|(i, fragment)| {
+            self.evaluate_fragment(coset, domain, i * fragment_size, fragment);
+        });
+        result
+    }
+
+    /// Evaluates the constraints over the points of the specified coset starting at the
+    /// specified offset, and writes the results into `result`.
+    fn evaluate_fragment(
+        &self,
+        coset: &CosetEvaluations<E>,
+        domain: &CosetDomain,
+        offset: usize,
+        result: &mut [E],
+    ) {
+        let trace_length = domain.trace_length();
+        let g = domain.trace_generator();
+
+        let mut main_frame = EvaluationFrame::new(coset.main.len());
+        let mut aux_frame = EvaluationFrame::new(coset.aux.len());
+        let mut periodic_values = vec![Felt::ZERO; coset.periodic.len()];
+        let mut tm_evaluations = vec![Felt::ZERO; self.main_coefficients.len()];
+        let mut ta_evaluations = vec![E::ZERO; self.aux_coefficients.len()];
+        let mut boundary_evaluations = vec![E::ZERO; self.boundary_divisors.len()];
+
+        let mut x = coset.offset * g.exp(offset as u64);
+        for (i, result) in result.iter_mut().enumerate() {
+            let step = i + offset;
+            let next_step = (step + 1) % trace_length;
+            read_frame_into(&coset.main, step, next_step, &mut main_frame);
+            read_frame_into(&coset.aux, step, next_step, &mut aux_frame);
+            for (value, column) in periodic_values.iter_mut().zip(coset.periodic.iter()) {
+                *value = column[step % column.len()];
+            }
+
+            // evaluate the transition constraints and divide their combination by the divisor
+            tm_evaluations.fill(Felt::ZERO);
+            self.air.evaluate_transition(&main_frame, &periodic_values, &mut tm_evaluations);
+            let mut transition = tm_evaluations
+                .iter()
+                .zip(self.main_coefficients.iter())
+                .fold(E::ZERO, |acc, (&evaluation, &coef)| acc + coef.mul_base(evaluation));
+
+            if !self.aux_coefficients.is_empty() {
+                ta_evaluations.fill(E::ZERO);
+                self.air.evaluate_aux_transition(
+                    &main_frame,
+                    &aux_frame,
+                    &periodic_values,
+                    &self.aux_rand_elements,
+                    &mut ta_evaluations,
+                );
+                transition += ta_evaluations
+                    .iter()
+                    .zip(self.aux_coefficients.iter())
+                    .fold(E::ZERO, |acc, (&evaluation, &coef)| acc + coef * evaluation);
+            }
+            let mut evaluation = transition.mul_base(coset.inv_transition_divisor[step]);
+
+            // evaluate the boundary constraints, combining the evaluations of the constraints
+            // with identical divisors before dividing them
+            boundary_evaluations.fill(E::ZERO);
+            let x_e = E::from(x);
+            for group in self.boundary_constraints.main_constraints() {
+                let idx = self.get_divisor_index(group.divisor());
+                for constraint in group.constraints() {
+                    let value = E::from(main_frame.current()[constraint.column()]);
+                    boundary_evaluations[idx] +=
+                        *constraint.cc() * constraint.evaluate_at(x_e, value);
+                }
+            }
+            for group in self.boundary_constraints.aux_constraints() {
+                let idx = self.get_divisor_index(group.divisor());
+                for constraint in group.constraints() {
+                    let value = aux_frame.current()[constraint.column()];
+                    boundary_evaluations[idx] +=
+                        *constraint.cc() * constraint.evaluate_at(x_e, value);
+                }
+            }
+            for (boundary, inv_divisor) in
+                boundary_evaluations.iter().zip(coset.inv_boundary_divisors.iter())
+            {
+                evaluation += boundary.mul_base(inv_divisor[step]);
+            }
+
+            *result = evaluation;
+            x *= g;
+        }
+    }
+
+    /// Returns the index of the specified boundary constraint divisor.
+    fn get_divisor_index(&self, divisor: &ConstraintDivisor<Felt>) -> usize {
+        self.boundary_divisors
+            .iter()
+            .position(|d| d == divisor)
+            .expect("unknown boundary constraint divisor")
+    }
+}
+
+// COSET EVALUATIONS
+// ================================================================================================
+
+/// Values needed to evaluate the constraints over a single coset of the trace domain.
+struct CosetEvaluations<E: FieldElement<BaseField = Felt>> {
+    /// Offset of the coset.
+    offset: Felt,
+    /// Evaluations of the main trace polynomials over the coset.
+    main: Vec<Vec<Felt>>,
+    /// Evaluations of the auxiliary trace polynomials over the coset.
+    aux: Vec<Vec<E>>,
+    /// Values of the periodic columns over a single cycle of the coset.
+    periodic: Vec<Vec<Felt>>,
+    /// Inverses of the evaluations of the transition constraint divisor over the coset.
+    inv_transition_divisor: Vec<Felt>,
+    /// Inverses of the evaluations of the boundary constraint divisors over the coset.
+    inv_boundary_divisors: Vec<Vec<Felt>>,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn read_frame_into<E: FieldElement>(
+    columns: &[Vec<E>],
+    step: usize,
+    next_step: usize,
+    frame: &mut EvaluationFrame<E>,
+) {
+    for (i, column) in columns.iter().enumerate() {
+        frame.current_mut()[i] = column[step];
+        frame.next_mut()[i] = column[next_step];
+    }
+}
+
+/// Returns the inverses of the evaluations of the specified divisor over the coset of the trace
+/// domain with the specified offset.
+///
+/// The numerator of a divisor is a product of terms `x^d - c`, where `d` divides the trace
+/// length. Over a coset of the trace domain, `x^d` takes on only `trace_length / d` distinct
+/// values; thus, each term needs to be evaluated and inverted over a single period only.
+fn evaluate_inv_divisor(
+    divisor: &ConstraintDivisor<Felt>,
+    domain: &CosetDomain,
+    coset_offset: Felt,
+) -> Vec<Felt> {
+    let trace_length = domain.trace_length();
+    let g = domain.trace_generator();
+    let mut result = vec![Felt::ONE; trace_length];
+
+    for &(degree, constant) in divisor.numerator() {
+        assert!(
+            degree.is_power_of_two() && degree <= trace_length,
+            "unsupported constraint divisor degree {degree}"
+        );
+        let period = trace_length / degree;
+        let h = g.exp(degree as u64);
+        let mut x_pow = coset_offset.exp(degree as u64);
+        let values = (0..period)
+            .map(|_| {
+                let value = x_pow - constant;
+                x_pow *= h;
+                value
+            })
+            .collect::<Vec<_>>();
+        let inv_values = batch_inversion(&values);
+        for (i, result) in result.iter_mut().enumerate() {
+            *result *= inv_values[i % period];
+        }
+    }
+
+    if !divisor.exemptions().is_empty() {
+        let mut x = coset_offset;
+        for result in result.iter_mut() {
+            *result *= divisor.evaluate_exemptions_at(x);
+            x *= g;
+        }
+    }
+
+    result
+}
+
+/// Returns the values of the periodic column with the specified polynomial over a single cycle
+/// of the coset of the trace domain with the specified offset.
+///
+/// The value of a periodic column with cycle length `m` at `x` is the value of its polynomial at
+/// `x^(trace_length / m)`; over a coset of the trace domain, these values repeat every `m` steps.
+fn evaluate_periodic_column(poly: &[Felt], domain: &CosetDomain, coset_offset: Felt) -> Vec<Felt> {
+    let cycle_length = poly.len();
+    let num_cycles = domain.trace_length() / cycle_length;
+    let h = Felt::get_root_of_unity(cycle_length.ilog2());
+    let mut x_pow = coset_offset.exp(num_cycles as u64);
+    (0..cycle_length)
+        .map(|_| {
+            let value = polynom::eval(poly, x_pow);
+            x_pow *= h;
+            value
+        })
+        .collect()
+}
@@ -0,0 +1,91 @@
+use processor::{
+    math::{fft, Felt, FieldElement, StarkField},
+    utils::collections::Vec,
+};
+use winter_prover::{Air, ColMatrix};
+
+// COSET DOMAIN
+// ================================================================================================
+
+/// Describes the domains over which the polynomials of a proof are evaluated.
+///
+/// The constraint evaluation and the LDE domains are cosets of subgroups which are `blowup`
+/// times larger than the trace domain. Each such domain is a union of `blowup` cosets of the
+/// trace domain: the coset with index `j` consists of points `offset * g^(j + k * blowup)` for
+/// `k` in `0..trace_length`, where `g` is the generator of the larger subgroup. Thus, the `k`-th
+/// point of the `j`-th coset is at position `j + k * blowup` of the larger domain, and a
+/// polynomial of degree smaller than the trace length can be evaluated over a single coset with
+/// an FFT of the trace length.
+pub struct CosetDomain {
+    trace_twiddles: Vec<Felt>,
+    ce_blowup: usize,
+    lde_blowup: usize,
+    offset: Felt,
+}
+
+impl CosetDomain {
+    /// Returns the domains for the computation described by the specified AIR.
+    pub fn new<A: Air<BaseField = Felt>>(air: &A) -> Self {
+        Self {
+            trace_twiddles: fft::get_twiddles(air.trace_length()),
+            ce_blowup: air.ce_blowup_factor(),
+            lde_blowup: air.options().blowup_factor(),
+            offset: air.domain_offset(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the length of the execution trace.
+    pub fn trace_length(&self) -> usize {
+        self.trace_twiddles.len() * 2
+    }
+
+    /// Returns the generator of the trace domain.
+    pub fn trace_generator(&self) -> Felt {
+        Felt::get_root_of_unity(self.trace_length().ilog2())
+    }
+
+    /// Returns the blowup factor of the constraint evaluation domain.
+    pub fn ce_blowup(&self) -> usize {
+        self.ce_blowup
+    }
+
+    /// Returns the blowup factor of the LDE domain.
+    pub fn lde_blowup(&self) -> usize {
+        self.lde_blowup
+    }
+
+    /// Returns the offset of the constraint evaluation and the LDE domains.
+    pub fn offset(&self) -> Felt {
+        self.offset
+    }
+
+    /// Returns the twiddles for evaluating polynomials over the trace domain.
+    pub fn trace_twiddles(&self) -> &[Felt] {
+        &self.trace_twiddles
+    }
+
+    /// Returns the offset of the coset with the specified index of a domain `blowup` times larger
+    /// than the trace domain.
+    pub fn coset_offset(&self, coset_idx: usize, blowup: usize) -> Felt {
+        let g = Felt::get_root_of_unity((self.trace_length() * blowup).ilog2());
+        self.offset * g.exp(coset_idx as u64)
+    }
+
+    // EVALUATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates the polynomials in the columns of the specified matrix over the coset of the
+    /// trace domain with the specified offset, and returns the evaluations column by column.
+    pub fn evaluate_over_coset<E>(&self, polys: &ColMatrix<E>, coset_offset: Felt) -> Vec<Vec<E>>
+    where
+        E: FieldElement<BaseField = Felt>,
+    {
+        polys
+            .columns()
+            .map(|poly| fft::evaluate_poly_with_offset(poly, &self.trace_twiddles, coset_offset, 1))
+            .collect()
+    }
+}
@@ -0,0 +1,266 @@
+//! This module contains a low-memory implementation of STARK proof generation.
+//!
+//! The STARK prover keeps the low-degree extensions (LDEs) of all trace segments and of the
+//! constraint composition polynomial in memory for the duration of proof generation, and for
+//! long traces, these take up several times more memory than the trace itself. The prover in
+//! this module generates exactly the same proofs, but keeps only the polynomials in coefficient
+//! form in memory:
+//! - Trace segments are extended one coset of the trace domain at a time, and the rows of each
+//!   coset are hashed into the leaves of the commitment Merkle tree before the next coset is
+//!   evaluated. The Merkle tree is released once its root has been sent to the verifier.
+//! - Constraints are evaluated over the constraint evaluation domain one coset of the trace
+//!   domain at a time as well.
+//! - The execution trace is released once all of its segments have been committed to.
+//! - In the query phase, the LDEs and the Merkle trees are recomputed from the polynomials to
+//!   read the rows at the queried positions and to build their authentication paths.
+//!
+//! Thus, the trace polynomials are evaluated over the LDE domain three times instead of once.
+use processor::{
+    math::{fft, Felt, FieldElement},
+    utils::collections::Vec,
+    ExecutionError,
+};
+use winter_air::{Air, AuxTraceRandElements, FieldExtension};
+use winter_fri::FriProver;
+use winter_prover::{
+    math::{
+        fields::{CubeExtension, QuadExtension},
+        ToElements,
+    },
+    CompositionPoly, Prover, StarkProof, Trace,
+};
+
+#[cfg(feature = "std")]
+use log::debug;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+mod channel;
+use channel::ProverChannel;
+
+mod commitment;
+use commitment::{commit_to_segment, query_segment};
+
+mod composer;
+use composer::{build_deep_composition_poly, get_ood_frame};
+
+mod constraints;
+use constraints::ConstraintEvaluator;
+
+mod domain;
+use domain::CosetDomain;
+
+// CHECKPOINT
+// ================================================================================================
+
+/// A step of proof generation upon completion of which the caller of [prove()] is notified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checkpoint {
+    /// The main trace segment has been committed to.
+    MainTraceCommitted,
+    /// An auxiliary trace segment has been committed to.
+    AuxTraceCommitted,
+    /// The constraint composition polynomial has been built.
+    ConstraintsEvaluated,
+    /// The constraint composition polynomial has been committed to.
+    ConstraintsCommitted,
+}
+
+// PROVER
+// ================================================================================================
+
+/// Generates a STARK proof attesting that the specified `trace` is a valid execution trace of the
+/// computation described by the AIR of the specified prover.
+///
+/// The proof is identical to the proof generated by [Prover::prove()]; only the options and the
+/// public inputs of the prover are used. `on_checkpoint` is invoked upon completion of each
+/// [Checkpoint].
+///
+/// # Errors
+/// Returns the error returned by `on_checkpoint`, if any; in this case, proof generation stops at
+/// the checkpoint.
+pub fn prove<P, C>(
+    prover: &P,
+    trace: P::Trace,
+    on_checkpoint: C,
+) -> Result<StarkProof, ExecutionError>
+where
+    P: Prover<BaseField = Felt>,
+    C: FnMut(Checkpoint) -> Result<(), ExecutionError>,
+{
+    match prover.options().field_extension() {
+        FieldExtension::None => generate_proof::<P, Felt, C>(prover, trace, on_checkpoint),
+        FieldExtension::Quadratic => {
+            generate_proof::<P, QuadExtension<Felt>, C>(prover, trace, on_checkpoint)
+        }
+        FieldExtension::Cubic => {
+            generate_proof::<P, CubeExtension<Felt>, C>(prover, trace, on_checkpoint)
+        }
+    }
+}
+
+fn generate_proof<P, E, C>(
+    prover: &P,
+    mut trace: P::Trace,
+    mut on_checkpoint: C,
+) -> Result<StarkProof, ExecutionError>
+where
+    P: Prover<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt>,
+    C: FnMut(Checkpoint) -> Result<(), ExecutionError>,
+{
+    // 0 ----- instantiate AIR and prover channel -------------------------------------------------
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let pub_inputs_elements = pub_inputs.to_elements();
+    let air = P::Air::new(trace.get_info(), pub_inputs, prover.options().clone());
+    let mut channel =
+        ProverChannel::<P::Air, E, P::HashFn, P::RandomCoin>::new(&air, pub_inputs_elements);
+    let domain = CosetDomain::new(&air);
+
+    // 1 ----- commit to the execution trace ------------------------------------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let main_polys = trace.main_segment().interpolate_columns();
+    channel.commit_trace(commit_to_segment::<Felt, P::HashFn>(&main_polys, &domain));
+    #[cfg(feature = "std")]
+    debug!(
+        "Committed to the main trace segment of {} columns in {} ms",
+        main_polys.num_cols(),
+        now.elapsed().as_millis()
+    );
+    on_checkpoint(Checkpoint::MainTraceCommitted)?;
+
+    let mut aux_segments = Vec::new();
+    let mut aux_polys = Vec::new();
+    let mut aux_rand_elements = AuxTraceRandElements::new();
+    for i in 0..trace.layout().num_aux_segments() {
+        #[cfg(feature = "std")]
+        let now = Instant::now();
+        let rand_elements = channel.get_aux_trace_segment_rand_elements(i);
+        let aux_segment = trace
+            .build_aux_segment(&aux_segments, &rand_elements)
+            .expect("failed to build auxiliary trace segment");
+        let segment_polys = aux_segment.interpolate_columns();
+        channel.commit_trace(commit_to_segment::<E, P::HashFn>(&segment_polys, &domain));
+        #[cfg(feature = "std")]
+        debug!(
+            "Committed to an auxiliary trace segment of {} columns in {} ms",
+            segment_polys.num_cols(),
+            now.elapsed().as_millis()
+        );
+
+        aux_segments.push(aux_segment);
+        aux_polys.push(segment_polys);
+        aux_rand_elements.add_segment_elements(rand_elements);
+        on_checkpoint(Checkpoint::AuxTraceCommitted)?;
+    }
+
+    // make sure the trace is valid against the AIR; this is a very expensive check, and thus, it
+    // is performed in debug mode only
+    #[cfg(debug_assertions)]
+    trace.validate(&air, &aux_segments, &aux_rand_elements);
+
+    // from now on, only the trace polynomials are needed
+    drop(trace);
+    drop(aux_segments);
+
+    // 2 ----- evaluate constraints ---------------------------------------------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let constraint_coeffs = channel.get_constraint_composition_coeffs();
+    let evaluator = ConstraintEvaluator::new(&air, aux_rand_elements, constraint_coeffs);
+    let mut composition_evaluations = evaluator.evaluate(&main_polys, &aux_polys, &domain);
+
+    let inv_twiddles = fft::get_inv_twiddles::<Felt>(composition_evaluations.len());
+    fft::interpolate_poly_with_offset(&mut composition_evaluations, &inv_twiddles, domain.offset());
+    let composition_poly = CompositionPoly::new(
+        composition_evaluations,
+        domain.trace_length(),
+        air.context().num_constraint_composition_columns(),
+    );
+    #[cfg(feature = "std")]
+    debug!(
+        "Evaluated constraints and built {} composition polynomial columns in {} ms",
+        composition_poly.num_columns(),
+        now.elapsed().as_millis()
+    );
+    on_checkpoint(Checkpoint::ConstraintsEvaluated)?;
+
+    // 3 ----- commit to constraint evaluations ---------------------------------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let composition_root = commit_to_segment::<E, P::HashFn>(composition_poly.data(), &domain);
+    channel.commit_constraints(composition_root);
+    #[cfg(feature = "std")]
+    debug!("Committed to the constraint evaluations in {} ms", now.elapsed().as_millis());
+    on_checkpoint(Checkpoint::ConstraintsCommitted)?;
+
+    // 4 ----- build DEEP composition polynomial --------------------------------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let z = channel.get_ood_point();
+    let ood_trace_states = get_ood_frame(&main_polys, &aux_polys, z, &domain);
+    channel.send_ood_trace_states(&ood_trace_states);
+    let ood_evaluations = composition_poly.evaluate_at(z);
+    channel.send_ood_constraint_evaluations(&ood_evaluations);
+
+    let deep_coefficients = channel.get_deep_composition_coeffs();
+    let deep_composition_poly = build_deep_composition_poly(
+        &main_polys,
+        &aux_polys,
+        &composition_poly,
+        &ood_trace_states,
+        &ood_evaluations,
+        z,
+        &deep_coefficients,
+        &domain,
+    );
+    #[cfg(feature = "std")]
+    debug!("Built DEEP composition polynomial in {} ms", now.elapsed().as_millis());
+
+    // 5 ----- compute FRI layers for the DEEP composition polynomial -----------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let deep_evaluations = fft::evaluate_poly_with_offset(
+        &deep_composition_poly,
+        domain.trace_twiddles(),
+        domain.offset(),
+        domain.lde_blowup(),
+    );
+    drop(deep_composition_poly);
+    let mut fri_prover = FriProver::new(air.options().to_fri_options());
+    fri_prover.build_layers(&mut channel, deep_evaluations);
+    #[cfg(feature = "std")]
+    debug!(
+        "Computed {} FRI layers from DEEP composition polynomial evaluations in {} ms",
+        fri_prover.num_layers(),
+        now.elapsed().as_millis()
+    );
+
+    // 6 ----- determine query positions ----------------------------------------------------------
+    channel.grind_query_seed();
+    let query_positions = channel.get_query_positions();
+
+    // 7 ----- build proof object -----------------------------------------------------------------
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let fri_proof = fri_prover.build_proof(&query_positions);
+    drop(fri_prover);
+
+    // the LDEs and their commitments are recomputed one at a time to answer the queries
+    let mut trace_queries =
+        vec![query_segment::<Felt, P::HashFn>(&main_polys, &domain, &query_positions)];
+    drop(main_polys);
+    for segment_polys in aux_polys.iter() {
+        trace_queries.push(query_segment::<E, P::HashFn>(segment_polys, &domain, &query_positions));
+    }
+    drop(aux_polys);
+    let constraint_queries =
+        query_segment::<E, P::HashFn>(composition_poly.data(), &domain, &query_positions);
+
+    let proof = channel.build_proof(trace_queries, constraint_queries, fri_proof);
+    #[cfg(feature = "std")]
+    debug!("Built proof object in {} ms", now.elapsed().as_millis());
+
+    Ok(proof)
+}
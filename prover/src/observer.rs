@@ -0,0 +1,181 @@
+//! This module contains the logic for reporting progress of proof generation and for cancelling
+//! proof generation between its phases.
+use super::{low_memory::Checkpoint, prove_trace};
+use processor::{math::Felt, ExecutionError, ExecutionTrace};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use winter_prover::{Prover, StarkProof};
+
+// PROVING PHASE
+// ================================================================================================
+
+/// A phase of execution proof generation.
+///
+/// Phases are listed in the order in which they are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProvingPhase {
+    /// Execution of the program and generation of the execution trace.
+    Execution,
+    /// Low-degree extension of the main execution trace and commitment to the extended trace.
+    MainTraceCommitment,
+    /// Generation and low-degree extension of the auxiliary execution trace, and commitment to
+    /// the extended trace.
+    AuxTraceCommitment,
+    /// Evaluation of the constraints over the constraint evaluation domain, and interpolation of
+    /// the constraint composition polynomial.
+    ConstraintEvaluation,
+    /// Evaluation of the constraint composition polynomial over the LDE domain and commitment to
+    /// the evaluations.
+    ConstraintCommitment,
+    /// Construction of the DEEP composition polynomial, generation of the FRI layers, query seed
+    /// grinding, and assembly of the proof.
+    Fri,
+}
+
+impl ProvingPhase {
+    /// The number of phases of execution proof generation.
+    pub const NUM_PHASES: u8 = 6;
+
+    /// Returns the position of this phase in the order of execution, starting with 0.
+    pub const fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the percentage of the phases of proof generation completed by the end of this
+    /// phase.
+    ///
+    /// Phases differ in cost, and the cost of each phase depends on the program and on the
+    /// proving options; thus, the percentage of completed phases is not proportional to the
+    /// elapsed proving time.
+    pub const fn progress(&self) -> u8 {
+        ((self.index() as u16 + 1) * 100 / Self::NUM_PHASES as u16) as u8
+    }
+}
+
+// PROVING OBSERVER
+// ================================================================================================
+
+/// Receives notifications about the progress of execution proof generation.
+pub trait ProvingObserver {
+    /// Invoked when the specified phase of proof generation starts.
+    fn on_phase_started(&mut self, _phase: ProvingPhase) {}
+
+    /// Invoked when the specified phase of proof generation finishes.
+    ///
+    /// `progress` is the percentage of the phases of proof generation completed so far, as
+    /// returned by [ProvingPhase::progress()].
+    fn on_phase_finished(&mut self, _phase: ProvingPhase, _progress: u8) {}
+}
+
+impl ProvingObserver for () {}
+
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to cancel proof generation from another thread.
+///
+/// Clones of a token share the same state; thus, cancelling any of the clones cancels the
+/// proof generation observing the token.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of the proof generation observing this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// PHASE TRACKER
+// ================================================================================================
+
+/// Tracks the current phase of proof generation, notifying the observer of phase transitions and
+/// checking the cancellation token between phases.
+///
+/// The tracker is invoked from the checkpoints of the prover, which may run on a different thread
+/// when deterministic proof generation is enabled; thus, its state is kept behind mutexes.
+pub(crate) struct PhaseTracker<'a, O: ProvingObserver> {
+    observer: Mutex<&'a mut O>,
+    cancellation: &'a CancellationToken,
+    phase: Mutex<Option<ProvingPhase>>,
+}
+
+impl<'a, O: ProvingObserver + Send> PhaseTracker<'a, O> {
+    pub fn new(observer: &'a mut O, cancellation: &'a CancellationToken) -> Self {
+        Self {
+            observer: Mutex::new(observer),
+            cancellation,
+            phase: Mutex::new(None),
+        }
+    }
+
+    /// Finishes the current phase (if any) and starts the specified phase.
+    ///
+    /// # Errors
+    /// Returns an error if proof generation has been cancelled; in this case, the next phase is
+    /// not started.
+    pub fn advance(&self, next: ProvingPhase) -> Result<(), ExecutionError> {
+        self.finish();
+        if self.cancellation.is_cancelled() {
+            return Err(ExecutionError::ProvingCancelled);
+        }
+        self.observer.lock().expect("observer lock poisoned").on_phase_started(next);
+        *self.phase.lock().expect("phase lock poisoned") = Some(next);
+        Ok(())
+    }
+
+    /// Finishes the current phase, if any.
+    pub fn finish(&self) {
+        let phase = self.phase.lock().expect("phase lock poisoned").take();
+        if let Some(phase) = phase {
+            let mut observer = self.observer.lock().expect("observer lock poisoned");
+            observer.on_phase_finished(phase, phase.progress());
+        }
+    }
+
+    /// Generates a STARK proof for the specified trace using the specified prover, reporting the
+    /// progress through the phases of proof generation.
+    ///
+    /// The STARK prover does not expose the boundaries between its phases; so, the proof is
+    /// always generated by the low-memory prover, and the phase transitions are reported and the
+    /// cancellation token is checked at its checkpoints.
+    ///
+    /// # Errors
+    /// Returns an error if proof generation fails or is cancelled.
+    pub fn prove<P>(
+        &self,
+        prover: P,
+        trace: ExecutionTrace,
+        deterministic: bool,
+    ) -> Result<StarkProof, ExecutionError>
+    where
+        P: Prover<BaseField = Felt, Trace = ExecutionTrace> + Send,
+    {
+        self.advance(ProvingPhase::MainTraceCommitment)?;
+
+        // the low-memory prover stops at the first checkpoint for which an error is returned
+        let proof = prove_trace(prover, trace, deterministic, true, |checkpoint| {
+            self.advance(match checkpoint {
+                Checkpoint::MainTraceCommitted => ProvingPhase::AuxTraceCommitment,
+                Checkpoint::AuxTraceCommitted => ProvingPhase::ConstraintEvaluation,
+                Checkpoint::ConstraintsEvaluated => ProvingPhase::ConstraintCommitment,
+                Checkpoint::ConstraintsCommitted => ProvingPhase::Fri,
+            })
+        })?;
+
+        self.finish();
+        Ok(proof)
+    }
+}
@@ -0,0 +1,76 @@
+use assembly::Assembler;
+use miden_prover::{prove, ExecutionProof, Program, ProvingOptions, StackInputs, StackOutputs};
+use processor::DefaultHost;
+use std::{env, fs, path::PathBuf};
+use verifier::{verify, ProgramInfo};
+
+/// Environment variable which, when set, makes the golden proof test overwrite the fixture with
+/// a newly generated proof instead of comparing against it.
+const UPDATE_ENV_VAR: &str = "UPDATE_GOLDEN_PROOF";
+
+const SOURCE: &str = "
+    begin
+        repeat.50
+            swap dup.1 add
+        end
+    end";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn deterministic_proving() {
+    let (_, _, _, proof1) = prove_program(ProvingOptions::default().with_deterministic_proving());
+    let (_, _, _, proof2) = prove_program(ProvingOptions::default().with_deterministic_proving());
+    assert_eq!(proof1.to_bytes(), proof2.to_bytes());
+
+    // deterministic proving is not a part of the STARK protocol parameters
+    let (_, _, _, proof3) = prove_program(ProvingOptions::default());
+    assert_eq!(proof1.stark_proof().options(), proof3.stark_proof().options());
+}
+
+/// Checks that proving a fixed program produces exactly the proof stored in the fixture file.
+///
+/// Any change to the AIR, the trace layout, or the proof serialization format changes the proof.
+/// If such a change is intended, the fixture can be regenerated by running this test with the
+/// `UPDATE_GOLDEN_PROOF` environment variable set.
+#[test]
+fn golden_proof() {
+    let (program, stack_inputs, stack_outputs, proof) =
+        prove_program(ProvingOptions::default().with_deterministic_proving());
+    let proof_bytes = proof.to_bytes();
+
+    let path = fixture_path();
+    if env::var_os(UPDATE_ENV_VAR).is_some() {
+        fs::write(&path, &proof_bytes).expect("failed to write golden proof");
+        return;
+    }
+
+    let expected = fs::read(&path).expect("failed to read golden proof");
+    assert!(
+        proof_bytes == expected,
+        "generated proof differs from the golden proof in {}; if the change is intended, \
+        regenerate the fixture by running this test with {UPDATE_ENV_VAR}=1",
+        path.display()
+    );
+
+    // the golden proof is a valid proof of the program's execution
+    let expected = ExecutionProof::from_bytes(&expected).unwrap();
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, stack_outputs, expected).is_ok());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_program(options: ProvingOptions) -> (Program, StackInputs, StackOutputs, ExecutionProof) {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_values([0, 1]).unwrap();
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    (program, stack_inputs, stack_outputs, proof)
+}
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden.proof")
+}
@@ -0,0 +1,72 @@
+use assembly::Assembler;
+use miden_prover::{
+    prove, ExecutionProof, FieldExtension, HashFunction, Program, ProvingOptions, StackInputs,
+    StackOutputs,
+};
+use processor::DefaultHost;
+use verifier::{verify, ProgramInfo};
+
+const SOURCE: &str = "
+    begin
+        repeat.50
+            swap dup.1 add
+        end
+        push.1.2.3.4 hperm dropw dropw
+        push.10 mem_store.0 mem_load.0 push.5 u32checked_add
+    end";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn low_memory_proving() {
+    let presets = [
+        ProvingOptions::default(),
+        ProvingOptions::new(27, 16, 0, FieldExtension::Cubic, 8, 127, HashFunction::Rpo256),
+        ProvingOptions::new(27, 8, 0, FieldExtension::None, 4, 31, HashFunction::Blake3_256),
+    ];
+
+    for options in presets {
+        let options = options.with_deterministic_proving();
+        let (program, stack_inputs, stack_outputs, proof) = prove_program(options.clone());
+        let (_, _, _, low_memory_proof) = prove_program(options.with_low_memory_proving());
+
+        // low-memory proof generation produces exactly the same proofs; the low-memory prover
+        // mirrors internals of the STARK prover, and thus, this also guards against changes to
+        // these internals in new versions of the winter-prover crate
+        assert!(proof.to_bytes() == low_memory_proof.to_bytes());
+
+        let program_info = ProgramInfo::from(program);
+        assert!(verify(program_info, stack_inputs, stack_outputs, low_memory_proof).is_ok());
+    }
+}
+
+#[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
+#[test]
+fn low_memory_proving_with_gpu() {
+    use miden_prover::ExecutionError;
+
+    // GPU acceleration is applied to RPO-based proofs, and cannot be used by the low-memory prover
+    let options = ProvingOptions::with_96_bit_security(true).with_low_memory_proving();
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_values([0, 1]).unwrap();
+    let result = prove(&program, stack_inputs, DefaultHost::default(), options);
+    assert!(matches!(result, Err(ExecutionError::UnsupportedProvingOptions(_))));
+
+    // proofs based on other hash functions are not affected
+    let options = ProvingOptions::default().with_deterministic_proving();
+    let (_, _, _, proof) = prove_program(options.clone());
+    let (_, _, _, low_memory_proof) = prove_program(options.with_low_memory_proving());
+    assert!(proof.to_bytes() == low_memory_proof.to_bytes());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_program(options: ProvingOptions) -> (Program, StackInputs, StackOutputs, ExecutionProof) {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_values([0, 1]).unwrap();
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    (program, stack_inputs, stack_outputs, proof)
+}
@@ -0,0 +1,177 @@
+use super::{
+    sorted_array::{sort_key, word_to_masm},
+    Felt, StarkField, TestError, Word, ONE,
+};
+use crate::build_test;
+use test_utils::{
+    crypto::{MerkleStore, Rpo256, RpoDigest},
+    rand::rand_array,
+};
+
+type AdvMapEntry = ([u8; 32], Vec<Felt>);
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn map_get() {
+    let entries = rand_entries(5);
+    let (com, advice_map) = commit(&entries);
+
+    for (key, value) in entries.iter() {
+        let source = get_source(&com, key);
+        let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map.clone());
+        let mut expected = vec![1];
+        expected.extend(word_to_stack(value));
+        expected.extend([1000, 1010]);
+        test.expect_stack(&expected);
+    }
+
+    // missing keys are mapped to the empty word
+    let mut key = entries[2].0;
+    key[0] += ONE;
+    let source = get_source(&com, &key);
+    let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map);
+    test.expect_stack(&[0, 0, 0, 0, 0, 1000, 1010]);
+}
+
+#[test]
+fn map_set_and_commit() {
+    let mut entries = rand_entries(4);
+    let (com, advice_map) = commit(&entries);
+
+    // update the value of an existing key and insert two new keys
+    let new_entries = [(entries[1].0, rand_array()), rand_entries(1)[0], rand_entries(1)[0]];
+    let sets = new_entries
+        .iter()
+        .map(|(key, value)| {
+            format!("push.{} push.{} exec.map::set", word_to_masm(key), word_to_masm(value))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // commit to the updated map, and reload it from the advice map to read one of the new values
+    let source = format!(
+        "
+        use.std::collections::map
+
+        begin
+            push.1000 push.{} exec.map::load
+            {sets}
+            exec.map::commit
+            dupw push.2000 movdn.4 exec.map::load
+            push.{} exec.map::get
+        end",
+        word_to_masm(&com),
+        word_to_masm(&new_entries[2].0),
+    );
+
+    entries[1].1 = new_entries[0].1;
+    entries.extend_from_slice(&new_entries[1..]);
+    entries.sort_by_key(|(key, _)| sort_key(key));
+    let (new_com, _) = commit(&entries);
+
+    let mut expected = word_to_stack(&new_entries[2].1);
+    expected.extend([2000, 2012]);
+    expected.extend(word_to_stack(&new_com));
+
+    let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn map_empty() {
+    let (key, value) = rand_entries(1)[0];
+    let source = format!(
+        "
+        use.std::collections::map
+
+        begin
+            push.1000 padw exec.map::load
+            dup.1 dup.1 exec.map::commit movup.5 movup.5
+            push.{} push.{} exec.map::set
+            exec.map::commit
+        end",
+        word_to_masm(&key),
+        word_to_masm(&value),
+    );
+
+    let (com, _) = commit(&[(key, value)]);
+    let mut expected = word_to_stack(&com);
+    expected.extend([0, 0, 0, 0]);
+    build_test!(&source, &[]).expect_stack(&expected);
+}
+
+#[test]
+fn map_load_fail() {
+    let mut entries = rand_entries(3);
+    let (com, _) = commit(&entries);
+    let source = get_source(&com, &entries[0].0);
+
+    // the entries in the advice map do not match the commitment
+    let mut values = flatten(&entries);
+    values[0] += ONE;
+    let advice_map = vec![(RpoDigest::from(com).as_bytes(), values)];
+    let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the entries are not sorted
+    entries.swap(0, 2);
+    let (com, advice_map) = commit(&entries);
+    let source = get_source(&com, &entries[0].0);
+    let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `n` random key-value pairs sorted by key.
+fn rand_entries(n: usize) -> Vec<(Word, Word)> {
+    let mut entries: Vec<(Word, Word)> = (0..n)
+        .map(|_| (Rpo256::hash_elements(&rand_array::<Felt, 4>()).into(), rand_array()))
+        .collect();
+    entries.sort_by_key(|(key, _)| sort_key(key));
+    entries
+}
+
+fn flatten(entries: &[(Word, Word)]) -> Vec<Felt> {
+    entries
+        .iter()
+        .flat_map(|(key, value)| key.iter().chain(value.iter()).copied())
+        .collect()
+}
+
+/// Returns the commitment to the specified entries, along with the advice map which contains the
+/// entries under the commitment.
+fn commit(entries: &[(Word, Word)]) -> (Word, Vec<AdvMapEntry>) {
+    let values = flatten(entries);
+    let com = Rpo256::hash_elements(&values);
+    (com.into(), vec![(com.as_bytes(), values)])
+}
+
+/// Returns the elements of the word in the order in which they appear on the stack once the word
+/// is loaded.
+fn word_to_stack(word: &Word) -> Vec<u64> {
+    word.iter().rev().map(|e| e.as_int()).collect()
+}
+
+/// Returns a program which loads the map with the specified commitment at address 1000, and looks
+/// up the specified key in it.
+fn get_source(com: &Word, key: &Word) -> String {
+    format!(
+        "
+        use.std::collections::map
+
+        begin
+            push.1000 push.{} exec.map::load
+            push.{} exec.map::contains
+            movdn.2
+            push.{} exec.map::get
+            movup.6
+        end",
+        word_to_masm(com),
+        word_to_masm(key),
+        word_to_masm(key),
+    )
+}
@@ -3,9 +3,11 @@ use test_utils::{
         init_merkle_leaf, init_merkle_leaves, MerkleError, MerkleStore, MerkleTree, Mmr, NodeIndex,
         RpoDigest,
     },
-    hash_elements, stack_to_ints, Felt, StarkField, Word, EMPTY_WORD, ONE, ZERO,
+    hash_elements, stack_to_ints, Felt, StarkField, TestError, Word, EMPTY_WORD, ONE, ZERO,
 };
 
+type AdvMapEntry = ([u8; 32], Vec<Felt>);
+
 // TESTS
 // ================================================================================================
 
@@ -803,6 +805,78 @@ fn test_mmr_large_add_roundtrip() {
     test.expect_stack_and_memory(&expect_stack, mmr_ptr, &expected_memory);
 }
 
+#[test]
+fn test_mmr_hash_peaks() {
+    let mut mmr = Mmr::new();
+    for i in 1..=7 {
+        mmr.add(init_merkle_leaf(i).into());
+    }
+    let accumulator = mmr.accumulator();
+
+    // store num_leaves followed by the peaks
+    let mut source = String::from("use.std::collections::mmr\nbegin\n");
+    source.push_str(&format!("push.{}.1000 mem_store\n", accumulator.num_leaves));
+    for (i, peak) in accumulator.peaks.iter().enumerate() {
+        let word = peak.iter().map(|e| e.as_int().to_string()).collect::<Vec<_>>();
+        source.push_str(&format!("push.{}.{} mem_storew dropw\n", word.join("."), 1001 + i));
+    }
+    source.push_str("push.1000 exec.mmr::hash_peaks\nend");
+
+    let expected: Vec<u64> = accumulator.hash_peaks().iter().rev().map(|e| e.as_int()).collect();
+    build_test!(&source, &[]).expect_stack(&expected);
+}
+
+#[test]
+fn test_mmr_verify_inclusion() {
+    let mut mmr = Mmr::new();
+    for i in 1..=7 {
+        mmr.add(init_merkle_leaf(i).into());
+    }
+    let (hash, advice_map) = mmr_advice_map(&mmr);
+
+    let mut store = MerkleStore::new();
+    for pos in 0..7 {
+        let proof = mmr.open(pos).unwrap();
+        store
+            .add_merkle_path(proof.relative_pos() as u64, mmr.get(pos).unwrap(), proof.merkle_path)
+            .unwrap();
+    }
+
+    let source = "
+        use.std::collections::mmr
+        begin
+            exec.mmr::verify_inclusion
+        end
+    ";
+
+    // every leaf can be verified at its position, including the single leaf peak
+    for pos in 0..7 {
+        let stack = verify_inclusion_stack(&hash, pos, mmr.get(pos).unwrap());
+        let test = build_test!(source, &stack, &[], store.clone(), advice_map.iter().cloned());
+        test.expect_stack(&[]);
+    }
+
+    // a leaf at the wrong position is rejected
+    for (pos, leaf_pos) in [(0, 1), (5, 4), (6, 0)] {
+        let stack = verify_inclusion_stack(&hash, pos, mmr.get(leaf_pos).unwrap());
+        let test = build_test!(source, &stack, &[], store.clone(), advice_map.iter().cloned());
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+
+    // positions past the last leaf are rejected
+    let stack = verify_inclusion_stack(&hash, 7, mmr.get(6).unwrap());
+    let test = build_test!(source, &stack, &[], store.clone(), advice_map.iter().cloned());
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // peaks which do not hash to the commitment are rejected
+    let mut values = advice_map[0].1.clone();
+    values[4] += ONE;
+    let bad_map = [(advice_map[0].0, values)];
+    let stack = verify_inclusion_stack(&hash, 0, mmr.get(0).unwrap());
+    let test = build_test!(source, &stack, &[], store, bad_map.iter().cloned());
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -813,3 +887,22 @@ fn digests_to_elements(digests: &[RpoDigest]) -> Vec<Felt> {
 fn digests_to_ints(digests: &[RpoDigest]) -> Vec<u64> {
     digests.iter().map(|d| Word::from(d)).flatten().map(|v| v.as_int()).collect()
 }
+
+/// Returns the hash of the MMR peaks, along with an advice map which contains the number of
+/// leaves and the padded peaks under the hash, as produced by `mmr::pack`.
+fn mmr_advice_map(mmr: &Mmr) -> (Word, Vec<AdvMapEntry>) {
+    let accumulator = mmr.accumulator();
+    let hash = accumulator.hash_peaks();
+
+    let mut map_data = vec![Felt::new(accumulator.num_leaves as u64), ZERO, ZERO, ZERO];
+    map_data.extend(accumulator.flatten_and_pad_peaks());
+    (hash, vec![(RpoDigest::new(hash).as_bytes(), map_data)])
+}
+
+/// Returns the stack inputs for `mmr::verify_inclusion`, i.e. [LEAF, pos, HASH].
+fn verify_inclusion_stack(hash: &Word, pos: usize, leaf: RpoDigest) -> Vec<u64> {
+    let mut stack = stack_to_ints(hash);
+    stack.push(pos as u64);
+    stack.extend(digests_to_ints(&[leaf]));
+    stack
+}
@@ -3,6 +3,9 @@ use test_utils::{
     Felt, StarkField, TestError, Word, EMPTY_WORD, ONE, ZERO,
 };
 
+mod map;
 mod mmr;
 mod smt;
 mod smt64;
+mod sorted_array;
+mod vector;
@@ -0,0 +1,148 @@
+use super::{Felt, StarkField, TestError, Word, ONE, ZERO};
+use crate::build_test;
+use test_utils::rand::rand_array;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn find_word() {
+    let words = sorted_words(8);
+    let start_ptr = 1000;
+    let end_ptr = start_ptr + words.len() as u64;
+
+    // all words in the array are found at their positions
+    for (i, word) in words.iter().enumerate() {
+        let ptr = start_ptr + i as u64;
+        let test = find_test("find_word", &words, word, start_ptr, end_ptr);
+        test.expect_stack(&[1, ptr, start_ptr, end_ptr]);
+    }
+
+    // a word smaller than all words in the array is not found
+    let word = [ZERO; 4];
+    let test = find_test("find_word", &words, &word, start_ptr, end_ptr);
+    test.expect_stack(&[0, start_ptr, start_ptr, end_ptr]);
+
+    // a word larger than all words in the array is not found
+    let word = [Felt::new(Felt::MODULUS - 1); 4];
+    let test = find_test("find_word", &words, &word, start_ptr, end_ptr);
+    test.expect_stack(&[0, end_ptr, start_ptr, end_ptr]);
+
+    // a word which differs from an existing one only in its least significant element is placed
+    // right after it
+    let mut word = words[3];
+    word[0] += ONE;
+    let test = find_test("find_word", &words, &word, start_ptr, end_ptr);
+    test.expect_stack(&[0, start_ptr + 4, start_ptr, end_ptr]);
+
+    // an empty array contains nothing
+    let test = find_test("find_word", &[], &words[0], start_ptr, start_ptr);
+    test.expect_stack(&[0, start_ptr, start_ptr, start_ptr]);
+}
+
+#[test]
+fn find_key_value() {
+    let keys = sorted_words(4);
+    let entries: Vec<Word> = keys.iter().flat_map(|key| [*key, rand_array()]).collect();
+    let start_ptr = 1000;
+    let end_ptr = start_ptr + entries.len() as u64;
+
+    for (i, key) in keys.iter().enumerate() {
+        let ptr = start_ptr + 2 * i as u64;
+        let test = find_test("find_key_value", &entries, key, start_ptr, end_ptr);
+        test.expect_stack(&[1, ptr, start_ptr, end_ptr]);
+    }
+
+    // values are not treated as keys
+    let test = find_test("find_key_value", &entries, &entries[1], start_ptr, end_ptr);
+    let result = test.get_last_stack_state();
+    assert_eq!(result[0], ZERO);
+    assert_eq!(result[1].as_int() % 2, start_ptr % 2);
+}
+
+#[test]
+fn assert_sorted() {
+    let source = |words: &[Word], proc: &str| {
+        format!(
+            "
+            use.std::collections::sorted_array
+
+            begin
+                {}
+                push.{}.1000
+                exec.sorted_array::{proc}
+            end",
+            store_words(1000, words),
+            1000 + words.len()
+        )
+    };
+
+    let mut words = sorted_words(6);
+    build_test!(&source(&words, "assert_sorted"), &[]).expect_stack(&[]);
+    build_test!(&source(&[], "assert_sorted"), &[]).expect_stack(&[]);
+
+    // repeated words are allowed in arrays of words, but not as keys of key-value pairs
+    words[1] = words[0];
+    words[2] = words[0];
+    build_test!(&source(&words, "assert_sorted"), &[]).expect_stack(&[]);
+    build_test!(&source(&words, "assert_key_value_sorted"), &[])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    words.swap(0, 4);
+    build_test!(&source(&words, "assert_sorted"), &[])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a test which stores `words` in memory starting at `start_ptr` and searches them for
+/// `key` using the specified procedure of the `sorted_array` module.
+fn find_test(
+    proc: &str,
+    words: &[Word],
+    key: &Word,
+    start_ptr: u64,
+    end_ptr: u64,
+) -> test_utils::Test {
+    let source = format!(
+        "
+        use.std::collections::sorted_array
+
+        begin
+            {}
+            push.{end_ptr}.{start_ptr}
+            push.{}
+            exec.sorted_array::{proc}
+        end",
+        store_words(start_ptr, words),
+        word_to_masm(key),
+    );
+    build_test!(&source, &[])
+}
+
+/// Returns `n` random words sorted in the order used by the `sorted_array` module.
+fn sorted_words(n: usize) -> Vec<Word> {
+    let mut words: Vec<Word> = (0..n).map(|_| rand_array()).collect();
+    words.sort_by_key(sort_key);
+    words
+}
+
+pub(super) fn sort_key(word: &Word) -> [u64; 4] {
+    [word[3].as_int(), word[2].as_int(), word[1].as_int(), word[0].as_int()]
+}
+
+pub(super) fn store_words(start_ptr: u64, words: &[Word]) -> String {
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            format!("push.{} push.{} mem_storew dropw", word_to_masm(word), start_ptr + i as u64)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(super) fn word_to_masm(word: &Word) -> String {
+    word.iter().map(|e| e.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
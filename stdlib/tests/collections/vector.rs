@@ -0,0 +1,100 @@
+use super::TestError;
+use crate::build_test;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn vector_ops() {
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.2.1000 exec.vector::new
+
+        push.1.2.3.4 push.1000 exec.vector::push
+        push.5.6.7.8 push.1000 exec.vector::push
+        push.9.10.11.12 push.0 push.1000 exec.vector::set
+
+        push.1000 exec.vector::pop
+        push.0 push.1000 exec.vector::get
+        push.1000 exec.vector::len
+        push.1000 exec.vector::capacity
+    end
+    ";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack_and_memory(
+        &[2, 1, 12, 11, 10, 9, 8, 7, 6, 5],
+        1000,
+        &[1, 2, 0, 0, 9, 10, 11, 12, 5, 6, 7, 8],
+    );
+}
+
+#[test]
+fn vector_clear() {
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.4.1000 exec.vector::new
+        push.1.2.3.4 push.1000 exec.vector::push
+        push.1000 exec.vector::clear
+        push.5.6.7.8 push.1000 exec.vector::push
+
+        push.1000 exec.vector::len
+        push.0 push.1000 exec.vector::get
+    end
+    ";
+
+    build_test!(source, &[]).expect_stack(&[8, 7, 6, 5, 1]);
+}
+
+#[test]
+fn vector_bounds_checks() {
+    // pushing onto a full vector
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.1.1000 exec.vector::new
+        push.1.2.3.4 push.1000 exec.vector::push
+        push.5.6.7.8 push.1000 exec.vector::push
+    end
+    ";
+    build_test!(source, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // popping from an empty vector
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.1.1000 exec.vector::new
+        push.1000 exec.vector::pop
+    end
+    ";
+    build_test!(source, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // reading past the end of the vector
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.4.1000 exec.vector::new
+        push.1.2.3.4 push.1000 exec.vector::push
+        push.1 push.1000 exec.vector::get
+    end
+    ";
+    build_test!(source, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // writing past the end of the vector
+    let source = "
+    use.std::collections::vector
+
+    begin
+        push.4.1000 exec.vector::new
+        push.1.2.3.4 push.1 push.1000 exec.vector::set
+    end
+    ";
+    build_test!(source, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
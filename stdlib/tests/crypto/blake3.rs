@@ -1,5 +1,9 @@
 use crate::build_test;
-use test_utils::{group_slice_elements, rand::rand_array, Felt, IntoBytes};
+use test_utils::{
+    group_slice_elements,
+    rand::{rand_array, rand_value},
+    Felt, IntoBytes,
+};
 
 #[test]
 fn blake3_hash_64_bytes() {
@@ -62,3 +66,74 @@ fn blake3_hash_32_bytes() {
     let test = build_test!(source, &ifelts);
     test.expect_stack(&ofelts);
 }
+
+#[test]
+fn blake3_hash_memory() {
+    let length = rand_value::<u64>() % 3072; // length: 0-3071
+    blake3_hash_memory_of_length(length as usize);
+}
+
+#[test]
+fn blake3_hash_memory_chunk_boundaries() {
+    // message lengths around block ( = 64 -bytes ) and chunk ( = 1024 -bytes ) boundaries
+    for length in [0, 1, 64, 65, 1024, 1025, 2048, 4097] {
+        blake3_hash_memory_of_length(length);
+    }
+}
+
+fn blake3_hash_memory_of_length(length: usize) {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        # mem.0 - input data address
+        push.10000 mem_store.0
+
+        # mem.1 - length in bytes
+        mem_store.1
+
+        # mem.2 - length in memory words
+        mem_load.1 u32checked_add.15 u32checked_div.16 mem_store.2
+
+        # Load input data into memory address 10000, 10001, ...
+        mem_load.2 u32checked_neq.0
+        while.true
+            mem_load.0 mem_storew dropw
+            mem_load.0 u32checked_add.1 mem_store.0
+            mem_load.2 u32checked_sub.1 dup mem_store.2 u32checked_neq.0
+        end
+
+        # Compute hash of memory address 10000, 10001, ...
+        mem_load.1
+        push.10000
+        exec.blake3::hash_memory
+    end";
+
+    // last memory word is filled up with random bytes, which must be ignored by the hasher
+    let ibytes = rand_bytes(length);
+    let ipadding = rand_bytes((16 - (length % 16)) % 16);
+
+    let ifelts = [
+        group_slice_elements::<u8, 4>(&[ibytes.clone(), ipadding].concat())
+            .iter()
+            .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+            .rev()
+            .collect::<Vec<u64>>(),
+        vec![length as u64; 1],
+    ]
+    .concat();
+
+    let hasher = blake3::hash(&ibytes);
+    let obytes = hasher.as_bytes();
+    let ofelts = group_slice_elements::<u8, 4>(obytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+fn rand_bytes(n: usize) -> Vec<u8> {
+    (0..n).map(|_| rand_value::<u64>() as u8).collect()
+}
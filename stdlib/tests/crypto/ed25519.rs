@@ -0,0 +1,83 @@
+use crate::build_test;
+use test_utils::{group_slice_elements, test_case, TestError};
+
+/// Because these tests are pretty expensive, they're by default ignored. If you're interested in
+/// running them, issue
+///
+/// cargo test --release -p miden-stdlib ed25519 -- --include-ignored
+///
+/// from root directory of Miden repository.
+///
+/// Test vectors are taken from section 7.1 of RFC 8032.
+#[test_case("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a", "", "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b" ; "0")]
+#[test_case("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c", "72", "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00" ; "1")]
+#[test_case("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025", "af82", "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a" ; "2")]
+#[ignore]
+fn verify(pub_key: &str, msg: &str, sig: &str) {
+    let (source, stack) =
+        prepare_verification(&decode_hex(pub_key), &decode_hex(msg), &decode_hex(sig));
+
+    let test = build_test!(&source, &stack);
+    test.expect_stack(&[]);
+}
+
+#[test_case("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c", "73", "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00" ; "tampered message")]
+#[test_case("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c", "72", "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c10" ; "non-canonical s")]
+#[ignore]
+fn verify_fails(pub_key: &str, msg: &str, sig: &str) {
+    let (source, stack) =
+        prepare_verification(&decode_hex(pub_key), &decode_hex(msg), &decode_hex(sig));
+
+    let test = build_test!(&source, &stack);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns program source, which writes the message to memory ( starting at address 10004 ) and
+/// verifies the signature, along with stack inputs holding public key, signature and message
+/// location.
+fn prepare_verification(pub_key: &[u8], msg: &[u8], sig: &[u8]) -> (String, Vec<u64>) {
+    let padding = vec![0; (16 - (msg.len() % 16)) % 16];
+    let words = group_slice_elements::<u8, 4>(&[msg, &padding].concat())
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes))
+        .collect::<Vec<u32>>();
+
+    let store_msg = words
+        .chunks(4)
+        .enumerate()
+        .map(|(i, w)| {
+            format!("push.{}.{}.{}.{} mem_storew.{} dropw", w[3], w[2], w[1], w[0], 10004 + i)
+        })
+        .collect::<Vec<_>>()
+        .join("\n        ");
+
+    let source = format!(
+        "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        {store_msg}
+        exec.ed25519::verify
+    end"
+    );
+
+    let mut stack = [pub_key, &sig[..32], &sig[32..]]
+        .concat()
+        .chunks(4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as u64)
+        .collect::<Vec<u64>>();
+    stack.extend_from_slice(&[10000, msg.len() as u64]);
+    stack.reverse();
+
+    (source, stack)
+}
+
+fn decode_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -1,6 +1,7 @@
 use crate::build_test;
 use sha3::{Digest, Keccak256};
 use test_utils::{
+    group_slice_elements,
     rand::{rand_array, rand_value},
     Felt, IntoBytes, STACK_TOP_SIZE,
 };
@@ -70,6 +71,73 @@ fn keccak256_2_to_1_hash() {
     test.expect_stack(&expected_stack);
 }
 
+#[test]
+fn keccak256_hash_memory() {
+    let length = rand_value::<u64>() & 1023; // length: 0-1023
+    keccak256_hash_memory_of_length(length as usize);
+}
+
+#[test]
+fn keccak256_hash_memory_block_boundaries() {
+    // message lengths around lane ( = 8 -bytes ) and rate ( = 136 -bytes ) boundaries
+    for length in [0, 1, 7, 8, 135, 136, 137, 272] {
+        keccak256_hash_memory_of_length(length);
+    }
+}
+
+fn keccak256_hash_memory_of_length(length: usize) {
+    let source = "
+    use.std::crypto::hashes::keccak256
+
+    begin
+        # mem.0 - input data address
+        push.10000 mem_store.0
+
+        # mem.1 - length in bytes
+        mem_store.1
+
+        # mem.2 - length in memory words
+        mem_load.1 u32checked_add.15 u32checked_div.16 mem_store.2
+
+        # Load input data into memory address 10000, 10001, ...
+        mem_load.2 u32checked_neq.0
+        while.true
+            mem_load.0 mem_storew dropw
+            mem_load.0 u32checked_add.1 mem_store.0
+            mem_load.2 u32checked_sub.1 dup mem_store.2 u32checked_neq.0
+        end
+
+        # Compute hash of memory address 10000, 10001, ...
+        mem_load.1
+        push.10000
+        exec.keccak256::hash_memory
+    end";
+
+    // last memory word is filled up with random bytes, which must be ignored by the hasher
+    let ibytes = rand_bytes(length);
+    let ipadding = rand_bytes((16 - (length % 16)) % 16);
+
+    let ifelts = [
+        group_slice_elements::<u8, 4>(&[ibytes.clone(), ipadding].concat())
+            .iter()
+            .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+            .rev()
+            .collect::<Vec<u64>>(),
+        vec![length as u64; 1],
+    ]
+    .concat();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&ibytes);
+    let digest = hasher.finalize();
+
+    let mut expected_stack = [0u64; STACK_TOP_SIZE >> 1];
+    to_stack(&digest, &mut expected_stack);
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&expected_stack);
+}
+
 /// Given N -many bytes ( such that N % 8 == 0 ), this function considers
 /// each block of contiguous 8 -bytes as little endian 64 -bit unsigned
 /// integer word and converts each u64 into two u32s such that first one holds
@@ -100,3 +168,7 @@ fn to_stack(i_digest: &[u8], stack: &mut [u64]) {
         stack[(i << 1) + 1] = low as u64;
     }
 }
+
+fn rand_bytes(n: usize) -> Vec<u8> {
+    (0..n).map(|_| rand_value::<u64>() as u8).collect()
+}
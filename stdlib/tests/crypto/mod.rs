@@ -3,9 +3,13 @@ mod falcon;
 
 mod blake3;
 mod ecdsa_secp256k1;
+mod ed25519;
 mod elgamal;
 mod fri;
 mod keccak256;
 mod native;
+#[cfg(feature = "std")]
+mod schnorr_ecgfp5;
 mod sha256;
-mod stark;
+mod sha3;
+mod sha512;
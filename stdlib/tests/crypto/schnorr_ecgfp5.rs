@@ -0,0 +1,200 @@
+use crate::math::ecgfp5::{base_field::Ext5, group::ECExt5};
+use miden_air::{Felt, StarkField};
+use processor::{Digest, SignatureKind};
+use test_utils::{
+    crypto::{MerkleStore, Rpo256},
+    rand::{rand_array, rand_vector},
+    AdviceInputs, AdviceProvider, MemAdviceProvider, Test, TestError, Word, ONE, ZERO,
+};
+
+/// Order of the ecgfp5 group, in radix-2^32 form with the least significant limb first.
+const GROUP_ORDER: [u32; 10] = [
+    2492202977, 3893352854, 3609501852, 3901250617, 3484943929, 2147483622, 22, 2147483633,
+    2147483655, 2147483645,
+];
+
+/// Positions of the public key point, of the encoding of R and of the limbs of s in a signature,
+/// as laid out on the advice stack (top first).
+const PK_RANGE: core::ops::Range<usize> = 0..10;
+const R_RANGE: core::ops::Range<usize> = 10..15;
+const S_RANGE: core::ops::Range<usize> = 15..25;
+
+#[test]
+fn test_schnorr_ecgfp5() {
+    let sk = gen_random_secret_key();
+    let message = rand_vector::<Felt>(4).try_into().unwrap();
+
+    let test = generate_test(&sk, &sk, message);
+    test.expect_stack(&[])
+}
+
+#[test]
+fn test_schnorr_ecgfp5_wrong_key() {
+    let sk = gen_random_secret_key();
+    let other_sk = gen_random_secret_key();
+    let message = rand_vector::<Felt>(4).try_into().unwrap();
+
+    // the host signs with a key which does not match the public key commitment
+    let test = generate_test(&sk, &other_sk, message);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_schnorr_ecgfp5_from_advice() {
+    let sk = gen_random_secret_key();
+    let message = rand_vector::<Felt>(4).try_into().unwrap();
+
+    let signature = sign(&sk, message);
+    let test = generate_from_advice_test(&sk, message, &signature);
+    test.expect_stack(&[])
+}
+
+#[test]
+fn test_schnorr_ecgfp5_tampered_message() {
+    let sk = gen_random_secret_key();
+    let message: Word = rand_vector::<Felt>(4).try_into().unwrap();
+    let mut other_message = message;
+    other_message[0] += ONE;
+
+    let signature = sign(&sk, message);
+    let test = generate_from_advice_test(&sk, other_message, &signature);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_schnorr_ecgfp5_tampered_signature() {
+    let sk = gen_random_secret_key();
+    let message = rand_vector::<Felt>(4).try_into().unwrap();
+    let signature = sign(&sk, message);
+
+    // tamper with one element of each component: the public key point, the encoding of R, and
+    // the least and the most significant limbs of s
+    for idx in [PK_RANGE.start, R_RANGE.start, S_RANGE.start, S_RANGE.end - 1] {
+        let mut tampered = signature.clone();
+        tampered[idx] = Felt::from(tampered[idx].as_int() as u32 ^ 1);
+
+        let test = generate_from_advice_test(&sk, message, &tampered);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn test_schnorr_ecgfp5_non_canonical_scalar() {
+    let sk = gen_random_secret_key();
+    let message = rand_vector::<Felt>(4).try_into().unwrap();
+    let mut signature = sign(&sk, message);
+
+    // s + N passes the curve equation check, since [N]G is the neutral element, and hence must be
+    // rejected by the canonicity check on s; limbs of s are read with the most significant first
+    let mut carry = 0u64;
+    for (limb, order_limb) in signature[S_RANGE].iter_mut().rev().zip(GROUP_ORDER) {
+        let sum = limb.as_int() + order_limb as u64 + carry;
+        *limb = Felt::from(sum as u32);
+        carry = sum >> 32;
+    }
+    assert_eq!(carry, 0, "s + N must fit into ten limbs");
+
+    let test = generate_from_advice_test(&sk, message, &signature);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+fn generate_test(sk: &[u32; 10], signing_sk: &[u32; 10], message: Word) -> Test {
+    let source = "
+    use.std::crypto::dsa::schnorr_ecgfp5
+
+    begin
+        exec.schnorr_ecgfp5::verify
+    end
+    ";
+
+    let pk = pub_key_commitment(&get_generator().scalar_mul(sk));
+    let signing_key = signing_sk.iter().map(|limb| Felt::from(*limb)).collect::<Vec<Felt>>();
+    let advice_map: Vec<([u8; 32], Vec<Felt>)> = vec![(pk.as_bytes(), signing_key)];
+
+    let message = message.into_iter().map(|a| a.as_int()).collect::<Vec<u64>>();
+
+    let mut op_stack = vec![];
+    op_stack.extend_from_slice(&message);
+    op_stack.extend_from_slice(&pk.as_elements().iter().map(|a| a.as_int()).collect::<Vec<u64>>());
+    let adv_stack = vec![];
+    let store = MerkleStore::new();
+
+    build_test!(source, &op_stack, &adv_stack, store, advice_map.into_iter())
+}
+
+/// Builds a test which verifies the provided signature, laid out as on the advice stack (top
+/// first), against the public key derived from `sk`.
+fn generate_from_advice_test(sk: &[u32; 10], message: Word, signature: &[Felt]) -> Test {
+    let source = "
+    use.std::crypto::dsa::schnorr_ecgfp5
+
+    begin
+        exec.schnorr_ecgfp5::verify_from_advice
+    end
+    ";
+
+    let pk = pub_key_commitment(&get_generator().scalar_mul(sk));
+
+    let mut op_stack = message.into_iter().map(|a| a.as_int()).collect::<Vec<u64>>();
+    op_stack.extend_from_slice(&pk.as_elements().iter().map(|a| a.as_int()).collect::<Vec<u64>>());
+    let adv_stack = signature.iter().map(|a| a.as_int()).collect::<Vec<u64>>();
+    let store = MerkleStore::new();
+
+    build_test!(source, &op_stack, &adv_stack, store)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Signs the message using the default host implementation, and returns the public key point and
+/// the signature as they are laid out on the advice stack (top first).
+fn sign(sk: &[u32; 10], message: Word) -> Vec<Felt> {
+    let pk = pub_key_commitment(&get_generator().scalar_mul(sk));
+    let signing_key = sk.iter().map(|limb| Felt::from(*limb)).collect::<Vec<Felt>>();
+    let advice_inputs = AdviceInputs::default().with_map([(pk.as_bytes(), signing_key)]);
+
+    // the host pushes the elements onto the advice stack one by one, so the last one ends on top
+    let mut signature = MemAdviceProvider::from(advice_inputs)
+        .get_signature(SignatureKind::SchnorrEcgfp5, pk.into(), message)
+        .unwrap();
+    signature.reverse();
+    signature
+}
+
+/// Generates a random secret key, i.e. a scalar lesser than the group order, as ten 32 -bit limbs.
+fn gen_random_secret_key() -> [u32; 10] {
+    let mut sk = rand_array::<u32, 10>();
+    sk[9] >>= 2;
+    sk
+}
+
+/// Generator of the group, whose encoding is w = 4.
+fn get_generator() -> ECExt5 {
+    ECExt5 {
+        x: Ext5::new(
+            11712523173042564207,
+            14090224426659529053,
+            13197813503519687414,
+            16280770174934269299,
+            15998333998318935536,
+        ),
+        y: Ext5::new(
+            14639054205878357578,
+            17426078571020221072,
+            2548978194165003307,
+            8663895577921260088,
+            9793640284382595140,
+        ),
+        point_at_infinity: ZERO,
+    }
+}
+
+/// Computes the commitment to a public key point, as expected by `schnorr_ecgfp5::verify`.
+fn pub_key_commitment(pk: &ECExt5) -> Digest {
+    let (x, y) = (pk.x, pk.y);
+    let t1 = Digest::new([x.a1, x.a0, ZERO, ZERO]);
+    let t2 = Digest::new([y.a0, x.a4, x.a3, x.a2]);
+    let t3 = Digest::new([y.a4, y.a3, y.a2, y.a1]);
+
+    Rpo256::merge(&[Rpo256::merge(&[t3, t2]), t1])
+}
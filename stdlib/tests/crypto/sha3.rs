@@ -0,0 +1,123 @@
+use crate::build_test;
+use sha3::{Digest, Keccak512, Sha3_256};
+use test_utils::{group_slice_elements, rand::rand_value};
+
+#[test]
+fn sha3_256_hash_memory() {
+    let length = rand_value::<u64>() & 1023; // length: 0-1023
+    let (ibytes, ifelts) = prepare_input(length as usize);
+
+    let digest = Sha3_256::digest(&ibytes);
+
+    let test = build_test!(source("sha3_256"), &ifelts);
+    test.expect_stack(&to_stack(&digest));
+}
+
+#[test]
+fn sha3_256_hash_memory_block_boundaries() {
+    // message lengths around rate ( = 136 -bytes ) boundaries
+    for length in [0, 135, 136, 137] {
+        let (ibytes, ifelts) = prepare_input(length);
+
+        let digest = Sha3_256::digest(&ibytes);
+
+        let test = build_test!(source("sha3_256"), &ifelts);
+        test.expect_stack(&to_stack(&digest));
+    }
+}
+
+#[test]
+fn keccak512_hash_memory() {
+    let length = rand_value::<u64>() & 1023; // length: 0-1023
+    let (ibytes, ifelts) = prepare_input(length as usize);
+
+    let digest = Keccak512::digest(&ibytes);
+
+    let test = build_test!(source("keccak512"), &ifelts);
+    test.expect_stack(&to_stack(&digest));
+}
+
+#[test]
+fn keccak512_hash_memory_block_boundaries() {
+    // message lengths around rate ( = 72 -bytes ) boundaries
+    for length in [0, 71, 72, 73, 144] {
+        let (ibytes, ifelts) = prepare_input(length);
+
+        let digest = Keccak512::digest(&ibytes);
+
+        let test = build_test!(source("keccak512"), &ifelts);
+        test.expect_stack(&to_stack(&digest));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a program which stores the message provided via the stack in memory, starting at
+/// address 10000, and hashes it using `hash_memory` procedure of the specified module.
+fn source(module: &str) -> String {
+    format!(
+        "
+    use.std::crypto::hashes::{module}
+
+    begin
+        # mem.0 - input data address
+        push.10000 mem_store.0
+
+        # mem.1 - length in bytes
+        mem_store.1
+
+        # mem.2 - length in memory words
+        mem_load.1 u32checked_add.15 u32checked_div.16 mem_store.2
+
+        # Load input data into memory address 10000, 10001, ...
+        mem_load.2 u32checked_neq.0
+        while.true
+            mem_load.0 mem_storew dropw
+            mem_load.0 u32checked_add.1 mem_store.0
+            mem_load.2 u32checked_sub.1 dup mem_store.2 u32checked_neq.0
+        end
+
+        # Compute hash of memory address 10000, 10001, ...
+        mem_load.1
+        push.10000
+        exec.{module}::hash_memory
+    end"
+    )
+}
+
+/// Returns a random message of the specified length together with stack inputs of the program
+/// returned by `source()`. Last memory word of the message is filled up with random bytes, which
+/// must be ignored by the hasher.
+fn prepare_input(length: usize) -> (Vec<u8>, Vec<u64>) {
+    let ibytes = rand_bytes(length);
+    let ipadding = rand_bytes((16 - (length % 16)) % 16);
+
+    let ifelts = [
+        group_slice_elements::<u8, 4>(&[ibytes.clone(), ipadding].concat())
+            .iter()
+            .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+            .rev()
+            .collect::<Vec<u64>>(),
+        vec![length as u64; 1],
+    ]
+    .concat();
+
+    (ibytes, ifelts)
+}
+
+/// Converts digest into a sequence of 32 -bit integers, such that each 8 -bytes of the digest,
+/// interpreted as little endian 64 -bit integer, are represented by its higher and lower 32 -bits.
+fn to_stack(digest: &[u8]) -> Vec<u64> {
+    group_slice_elements::<u8, 8>(digest)
+        .iter()
+        .flat_map(|&bytes| {
+            let word = u64::from_le_bytes(bytes);
+            [word >> 32, word & 0xffff_ffff]
+        })
+        .collect()
+}
+
+fn rand_bytes(n: usize) -> Vec<u8> {
+    (0..n).map(|_| rand_value::<u64>() as u8).collect()
+}
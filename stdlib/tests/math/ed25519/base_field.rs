@@ -0,0 +1,167 @@
+use crate::build_test;
+use num_bigint::BigUint;
+use test_utils::rand::rand_vector;
+
+// FIELD OPERATIONS
+// ================================================================================================
+
+#[test]
+fn test_ed25519_base_field_mul() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::mul
+    end";
+
+    let p = prime();
+    let operands = [
+        (rand_element(), rand_element()),
+        (&p - 1u32, &p - 1u32),
+        (&p - 1u32, BigUint::from(2u32)),
+        (BigUint::from(0u32), rand_element()),
+    ];
+
+    for (a, b) in operands {
+        let c = (&a * &b) % &p;
+        build_test!(source, &to_stack(&[&a, &b])).expect_stack(&to_limbs(&c));
+    }
+}
+
+#[test]
+fn test_ed25519_base_field_add() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::add
+    end";
+
+    let p = prime();
+    let operands = [
+        (rand_element(), rand_element()),
+        (&p - 1u32, &p - 1u32),
+        (&p - 1u32, BigUint::from(1u32)),
+        (&p - 19u32, BigUint::from(18u32)),
+    ];
+
+    for (a, b) in operands {
+        let c = (&a + &b) % &p;
+        build_test!(source, &to_stack(&[&a, &b])).expect_stack(&to_limbs(&c));
+    }
+}
+
+#[test]
+fn test_ed25519_base_field_sub() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::sub
+    end";
+
+    let p = prime();
+    let operands = [
+        (rand_element(), rand_element()),
+        (BigUint::from(0u32), &p - 1u32),
+        (BigUint::from(1u32), BigUint::from(2u32)),
+        (&p - 1u32, &p - 1u32),
+    ];
+
+    for (a, b) in operands {
+        let c = (&a + &p - &b) % &p;
+        build_test!(source, &to_stack(&[&a, &b])).expect_stack(&to_limbs(&c));
+    }
+}
+
+#[test]
+fn test_ed25519_base_field_neg() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::neg
+    end";
+
+    let p = prime();
+    for a in [rand_element(), BigUint::from(0u32), BigUint::from(1u32)] {
+        let b = (&p - &a) % &p;
+        build_test!(source, &to_stack(&[&a])).expect_stack(&to_limbs(&b));
+    }
+}
+
+#[test]
+fn test_ed25519_base_field_eq() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::eq
+    end";
+
+    let a = rand_element();
+    let b = (&a + 1u32) % prime();
+
+    build_test!(source, &to_stack(&[&a, &a])).expect_stack(&[1]);
+    build_test!(source, &to_stack(&[&a, &b])).expect_stack(&[0]);
+}
+
+#[test]
+fn test_ed25519_base_field_inv() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::inv
+    end";
+
+    let p = prime();
+    let a = rand_element();
+    let b = a.modpow(&(&p - 2u32), &p);
+
+    build_test!(source, &to_stack(&[&a])).expect_stack(&to_limbs(&b));
+}
+
+#[test]
+fn test_ed25519_base_field_pow_p58() {
+    let source = "
+    use.std::math::ed25519::base_field
+
+    begin
+        exec.base_field::pow_p58
+    end";
+
+    let p = prime();
+    let a = rand_element();
+    let b = a.modpow(&((&p - 5u32) >> 3), &p);
+
+    build_test!(source, &to_stack(&[&a])).expect_stack(&to_limbs(&b));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns ed25519 base field prime p = 2^255 - 19.
+fn prime() -> BigUint {
+    (BigUint::from(1u32) << 255) - 19u32
+}
+
+fn rand_element() -> BigUint {
+    let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
+    BigUint::new(limbs) % prime()
+}
+
+/// Returns eight 32 -bit limbs of the provided number, in the order they're expected
+/// to be found on the stack after execution of a routine ( i.e. least significant limb first ).
+fn to_limbs(a: &BigUint) -> Vec<u64> {
+    let mut limbs = a.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns stack inputs s.t. limbs of the first provided number end up on top of the stack.
+fn to_stack(elements: &[&BigUint]) -> Vec<u64> {
+    let mut stack = elements.iter().flat_map(|&a| to_limbs(a)).collect::<Vec<_>>();
+    stack.reverse();
+    stack
+}
@@ -0,0 +1,248 @@
+use crate::build_test;
+use test_utils::{test_case, TestError};
+
+// Encodings ( as defined in RFC 8032 ) of ed25519 points, as eight little-endian 32 -bit limbs
+const P: [u32; 8] = [
+    777736915, 970553882, 2567847544, 1595397348, 998784138, 2814614087, 3248454631, 159652938,
+];
+const Q: [u32; 8] = [
+    4024537892, 959363840, 248140971, 3848973111, 1999413317, 3001904191, 3059723779, 3572850112,
+];
+const P_PLUS_Q: [u32; 8] = [
+    1425074391, 3453349025, 698513922, 2361582351, 182907161, 2067725252, 4255919785, 808606820,
+];
+const P_DOUBLE: [u32; 8] = [
+    1157606283, 1007731607, 456233418, 995242986, 253418220, 1160649780, 2740420606, 1741887690,
+];
+const P_NEG: [u32; 8] = [
+    777736915, 970553882, 2567847544, 1595397348, 998784138, 2814614087, 3248454631, 2307136586,
+];
+const SCALAR: [u32; 8] = [
+    4192983756, 949539216, 200071088, 2390857534, 3687093963, 571981485, 1243862422, 225023560,
+];
+const SCALAR_P: [u32; 8] = [
+    2813797154, 3222574209, 4175320132, 1853123995, 1462264781, 2177350314, 433485190, 3940000106,
+];
+const SCALAR_B: [u32; 8] = [
+    2785661745, 2995200603, 1125000492, 1562026946, 2033434564, 2848656696, 1484086262, 1509405036,
+];
+const SCALAR_B_PLUS_SEVEN_P: [u32; 8] = [
+    857871325, 3573740094, 1504989918, 828955926, 719275125, 3644722670, 3829949088, 1218514437,
+];
+const SEVEN_B: [u32; 8] = [
+    2671796920, 314885301, 4155241021, 4035455814, 3711348033, 2278450280, 726127919, 2982503395,
+];
+
+#[test]
+fn test_ed25519_point_decompress_compress() {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1000
+        exec.group::compress
+    end",
+        push_limbs(&P)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&P));
+}
+
+#[test]
+fn test_ed25519_point_decompress_invalid() {
+    // there's no x s.t. ( x, 2 ) lies on the curve
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+    end",
+        push_limbs(&[2, 0, 0, 0, 0, 0, 0, 0])
+    );
+
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_ed25519_point_addition() {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1008
+        {}
+        exec.group::decompress
+
+        push.1016
+        push.1008
+        push.1000
+        exec.group::add
+
+        push.1016
+        exec.group::compress
+    end",
+        push_limbs(&P),
+        push_limbs(&Q)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&P_PLUS_Q));
+}
+
+#[test]
+fn test_ed25519_point_doubling() {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1000
+        push.1000
+        exec.group::double
+
+        push.1000
+        exec.group::compress
+    end",
+        push_limbs(&P)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&P_DOUBLE));
+}
+
+#[test]
+fn test_ed25519_point_negation() {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1008
+        push.1000
+        exec.group::neg
+
+        push.1008
+        exec.group::compress
+    end",
+        push_limbs(&P)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&P_NEG));
+}
+
+#[test_case(P, SCALAR, SCALAR_P ; "0")]
+#[ignore]
+fn test_ed25519_point_multiplication(src: [u32; 8], scalar: [u32; 8], dst: [u32; 8]) {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1000
+        {}
+        push.1000
+        exec.group::mul
+
+        push.1000
+        exec.group::compress
+    end",
+        push_limbs(&src),
+        push_limbs(&scalar)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&dst));
+}
+
+#[test_case([7, 0, 0, 0, 0, 0, 0, 0], SEVEN_B ; "0")]
+#[test_case(SCALAR, SCALAR_B ; "1")]
+#[ignore]
+fn test_ed25519_generator_multiplication(scalar: [u32; 8], dst: [u32; 8]) {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::gen_mul
+
+        push.1000
+        exec.group::compress
+    end",
+        push_limbs(&scalar)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&dst));
+}
+
+#[test_case(SCALAR, P, [7, 0, 0, 0, 0, 0, 0, 0], SCALAR_B_PLUS_SEVEN_P ; "0")]
+#[ignore]
+fn test_ed25519_double_scalar_multiplication(
+    s: [u32; 8],
+    src: [u32; 8],
+    k: [u32; 8],
+    dst: [u32; 8],
+) {
+    let source = format!(
+        "
+    use.std::math::ed25519::group
+
+    begin
+        push.1000
+        {}
+        exec.group::decompress
+
+        push.1000
+        {}
+        push.1000
+        {}
+        exec.group::double_scalar_mul
+
+        push.1000
+        exec.group::compress
+    end",
+        push_limbs(&src),
+        push_limbs(&k),
+        push_limbs(&s)
+    );
+
+    build_test!(&source).expect_stack(&to_stack_outputs(&dst));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns instructions pushing provided limbs on stack, s.t. first limb ends up on top.
+fn push_limbs(limbs: &[u32; 8]) -> String {
+    format!(
+        "push.{}.{}.{}.{}\n        push.{}.{}.{}.{}",
+        limbs[7], limbs[6], limbs[5], limbs[4], limbs[3], limbs[2], limbs[1], limbs[0]
+    )
+}
+
+fn to_stack_outputs(limbs: &[u32; 8]) -> Vec<u64> {
+    limbs.iter().map(|&v| v as u64).collect()
+}
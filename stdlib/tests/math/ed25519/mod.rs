@@ -0,0 +1,3 @@
+mod base_field;
+mod group;
+mod scalar_field;
@@ -0,0 +1,61 @@
+use crate::build_test;
+use num_bigint::BigUint;
+use test_utils::rand::rand_vector;
+
+#[test]
+fn test_ed25519_scalar_field_reduce_512() {
+    let source = "
+    use.std::math::ed25519::scalar_field
+
+    begin
+        exec.scalar_field::reduce_512
+    end";
+
+    let l = order();
+    let max = (BigUint::from(1u32) << 512) - 1u32;
+    for a in [rand_u512(), max, l.clone(), &l - 1u32] {
+        let b = &a % &l;
+        build_test!(source, &to_stack(&a, 16)).expect_stack(&to_limbs(&b, 8));
+    }
+}
+
+#[test]
+fn test_ed25519_scalar_field_is_canonical() {
+    let source = "
+    use.std::math::ed25519::scalar_field
+
+    begin
+        exec.scalar_field::is_canonical
+    end";
+
+    let l = order();
+    let max = (BigUint::from(1u32) << 256) - 1u32;
+    for (a, flag) in [(&l - 1u32, 1), (l.clone(), 0), (&l + 1u32, 0), (max, 0), (&l >> 1, 1)] {
+        build_test!(source, &to_stack(&a, 8)).expect_stack(&[flag]);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns order of ed25519 prime subgroup l = 2^252 + 27742317777372353535851937790883648493.
+fn order() -> BigUint {
+    (BigUint::from(1u32) << 252) + BigUint::from(27742317777372353535851937790883648493u128)
+}
+
+fn rand_u512() -> BigUint {
+    let limbs = rand_vector::<u64>(16).iter().map(|&v| v as u32).collect::<Vec<_>>();
+    BigUint::new(limbs)
+}
+
+fn to_limbs(a: &BigUint, n: usize) -> Vec<u64> {
+    let mut limbs = a.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(n, 0);
+    limbs
+}
+
+fn to_stack(a: &BigUint, n: usize) -> Vec<u64> {
+    let mut stack = to_limbs(a, n);
+    stack.reverse();
+    stack
+}
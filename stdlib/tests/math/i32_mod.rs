@@ -0,0 +1,287 @@
+use crate::build_test;
+use test_utils::{proptest::prelude::*, TestError, U32_BOUND};
+
+// SIGN
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_neg() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_neg
+        end";
+
+    build_test!(source, &[to_stack(5)]).expect_stack(&[to_stack(-5)]);
+    build_test!(source, &[to_stack(i32::MAX)]).expect_stack(&[to_stack(-i32::MAX)]);
+
+    // negating -2^31 overflows
+    build_test!(source, &[to_stack(i32::MIN)])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn sign_extend() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::sign_extend_8
+            swap
+            exec.i32::sign_extend_16
+        end";
+
+    build_test!(source, &[0xff80, 0x1ff]).expect_stack(&[to_stack(-128), to_stack(-1)]);
+    build_test!(source, &[0x7fff, 0x7f]).expect_stack(&[to_stack(i16::MAX as i32), 0x7f]);
+}
+
+// ADDITION AND SUBTRACTION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_add_fail() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_add
+        end";
+
+    build_test!(source, &[to_stack(i32::MAX), 1])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+    build_test!(source, &[to_stack(i32::MIN), to_stack(-1)])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // u32 assertion failure
+    build_test!(source, &[U32_BOUND, 1]).expect_error(TestError::ExecutionError("NotU32Value"));
+    build_test!(source, &[1, U32_BOUND]).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn checked_sub_fail() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_sub
+        end";
+
+    build_test!(source, &[to_stack(i32::MIN), 1])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+    build_test!(source, &[0, to_stack(i32::MIN)])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// MULTIPLICATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn overflowing_mul() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::overflowing_mul
+        end";
+
+    // test edge cases around the bounds; randomized tests are done using proptest
+    let values = [0, 1, -1, 2, -2, 46341, -46341, 65536, i32::MAX, i32::MIN, i32::MIN / 2];
+    for a in values {
+        for b in values {
+            let (c, overflow) = a.overflowing_mul(b);
+            build_test!(source, &[to_stack(a), to_stack(b)])
+                .expect_stack(&[overflow as u64, to_stack(c)]);
+        }
+    }
+}
+
+#[test]
+fn checked_mul_fail() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_mul
+        end";
+
+    build_test!(source, &[to_stack(i32::MIN), to_stack(-1)])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+    build_test!(source, &[65536, 32768]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// DIVISION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_divmod() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_divmod
+        end";
+
+    let values = [1, -1, 7, -7, 3, -3, i32::MAX, i32::MIN];
+    for a in values {
+        for b in values {
+            if a == i32::MIN && b == -1 {
+                continue;
+            }
+            build_test!(source, &[to_stack(a), to_stack(b)])
+                .expect_stack(&[to_stack(a % b), to_stack(a / b)]);
+        }
+    }
+}
+
+#[test]
+fn checked_divmod_fail() {
+    let source = "
+        use.std::math::i32
+        begin
+            exec.i32::checked_divmod
+        end";
+
+    // division by zero
+    build_test!(source, &[to_stack(-5), 0]).expect_error(TestError::ExecutionError("DivideByZero"));
+
+    // quotient overflow
+    build_test!(source, &[to_stack(i32::MIN), to_stack(-1)])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // u32 assertion failure
+    build_test!(source, &[U32_BOUND, 1]).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    #[test]
+    fn wrapping_neg_proptest(a in any::<i32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::wrapping_neg
+            end";
+
+        build_test!(source, &[to_stack(a)]).prop_expect_stack(&[to_stack(a.wrapping_neg())])?;
+    }
+
+    #[test]
+    fn sign_extend_proptest(a in any::<u32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                dup
+                exec.i32::sign_extend_8
+                swap
+                exec.i32::sign_extend_16
+                swap
+            end";
+
+        let c8 = a as i8 as i32;
+        let c16 = a as i16 as i32;
+        build_test!(source, &[a as u64]).prop_expect_stack(&[to_stack(c8), to_stack(c16)])?;
+    }
+
+    #[test]
+    fn overflowing_add_proptest(a in any::<i32>(), b in any::<i32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::overflowing_add
+            end";
+
+        let (c, overflow) = a.overflowing_add(b);
+        build_test!(source, &[to_stack(a), to_stack(b)])
+            .prop_expect_stack(&[overflow as u64, to_stack(c)])?;
+    }
+
+    #[test]
+    fn overflowing_sub_proptest(a in any::<i32>(), b in any::<i32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::overflowing_sub
+            end";
+
+        let (c, overflow) = a.overflowing_sub(b);
+        build_test!(source, &[to_stack(a), to_stack(b)])
+            .prop_expect_stack(&[overflow as u64, to_stack(c)])?;
+    }
+
+    #[test]
+    fn overflowing_mul_proptest(a in any::<i32>(), b in any::<i32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::overflowing_mul
+            end";
+
+        let (c, overflow) = a.overflowing_mul(b);
+        build_test!(source, &[to_stack(a), to_stack(b)])
+            .prop_expect_stack(&[overflow as u64, to_stack(c)])?;
+    }
+
+    #[test]
+    fn checked_div_proptest(a in any::<i32>(), b in any::<i32>()) {
+        prop_assume!(b != 0 && !(a == i32::MIN && b == -1));
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::checked_div
+            end";
+
+        build_test!(source, &[to_stack(a), to_stack(b)]).prop_expect_stack(&[to_stack(a / b)])?;
+    }
+
+    #[test]
+    fn checked_mod_proptest(a in any::<i32>(), b in any::<i32>()) {
+        prop_assume!(b != 0 && !(a == i32::MIN && b == -1));
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::checked_mod
+            end";
+
+        build_test!(source, &[to_stack(a), to_stack(b)]).prop_expect_stack(&[to_stack(a % b)])?;
+    }
+
+    #[test]
+    fn comparisons_proptest(a in any::<i32>(), b in any::<i32>()) {
+        let source = "
+            use.std::math::i32
+            begin
+                dup.1 dup.1 exec.i32::lt movdn.2
+                dup.1 dup.1 exec.i32::lte movdn.2
+                dup.1 dup.1 exec.i32::gt movdn.2
+                dup.1 dup.1 exec.i32::gte movdn.2
+                dup.1 dup.1 exec.i32::min movdn.2
+                exec.i32::max
+            end";
+
+        let expected = [
+            to_stack(a.max(b)),
+            to_stack(a.min(b)),
+            (a >= b) as u64,
+            (a > b) as u64,
+            (a <= b) as u64,
+            (a < b) as u64,
+        ];
+        build_test!(source, &[to_stack(a), to_stack(b)]).prop_expect_stack(&expected)?;
+    }
+
+    #[test]
+    fn shr_proptest(a in any::<i32>(), b in 0_u32..32) {
+        let source = "
+            use.std::math::i32
+            begin
+                exec.i32::shr
+            end";
+
+        build_test!(source, &[5, to_stack(a), b as u64])
+            .prop_expect_stack(&[to_stack(a >> b), 5])?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the two's complement representation of the provided value as a stack element.
+fn to_stack(value: i32) -> u64 {
+    value as u32 as u64
+}
@@ -0,0 +1,258 @@
+use crate::build_test;
+use test_utils::{proptest::prelude::*, TestError, U32_BOUND};
+
+// SIGN
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_neg() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::checked_neg
+        end";
+
+    let (a1, a0) = split_i64(i64::MAX);
+    let (c1, c0) = split_i64(-i64::MAX);
+    build_test!(source, &[a0, a1]).expect_stack(&[c1, c0]);
+
+    // negating -2^63 overflows
+    let (a1, a0) = split_i64(i64::MIN);
+    build_test!(source, &[a0, a1]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn from_i32() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::from_i32
+        end";
+
+    for a in [0, 1, -1, i32::MAX, i32::MIN] {
+        let (c1, c0) = split_i64(a as i64);
+        build_test!(source, &[a as u32 as u64]).expect_stack(&[c1, c0]);
+    }
+}
+
+// ADDITION AND SUBTRACTION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_add_fail() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::checked_add
+        end";
+
+    let (a1, a0) = split_i64(i64::MAX);
+    build_test!(source, &[a0, a1, 1, 0]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // u32 limb assertion failure
+    for i in 0..4 {
+        let mut stack_init = [1, 2, 3, 4];
+        stack_init[i] = U32_BOUND;
+        let test = build_test!(source, &stack_init);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
+#[test]
+fn checked_sub_fail() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::checked_sub
+        end";
+
+    let (a1, a0) = split_i64(i64::MIN);
+    build_test!(source, &[a0, a1, 1, 0]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// MULTIPLICATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn overflowing_mul() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::overflowing_mul
+        end";
+
+    // test edge cases around the bounds; randomized tests are done using proptest
+    let values = [0, 1, -1, 3037000500, -3037000500, 1 << 32, i64::MAX, i64::MIN, i64::MIN / 2];
+    for a in values {
+        for b in values {
+            let (c, overflow) = a.overflowing_mul(b);
+            let (a1, a0) = split_i64(a);
+            let (b1, b0) = split_i64(b);
+            let (c1, c0) = split_i64(c);
+            build_test!(source, &[a0, a1, b0, b1]).expect_stack(&[overflow as u64, c1, c0]);
+        }
+    }
+}
+
+// DIVISION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_divmod() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::checked_divmod
+        end";
+
+    let values = [1, -1, 7, -7, 1 << 40, -(1 << 40), i64::MAX, i64::MIN];
+    for a in values {
+        for b in values {
+            if a == i64::MIN && b == -1 {
+                continue;
+            }
+            let (a1, a0) = split_i64(a);
+            let (b1, b0) = split_i64(b);
+            let (q1, q0) = split_i64(a / b);
+            let (r1, r0) = split_i64(a % b);
+            build_test!(source, &[a0, a1, b0, b1]).expect_stack(&[r1, r0, q1, q0]);
+        }
+    }
+}
+
+#[test]
+fn checked_divmod_fail() {
+    let source = "
+        use.std::math::i64
+        begin
+            exec.i64::checked_divmod
+        end";
+
+    // division by zero
+    let (a1, a0) = split_i64(-5);
+    build_test!(source, &[a0, a1, 0, 0]).expect_error(TestError::ExecutionError("DivideByZero"));
+
+    // quotient overflow
+    let (a1, a0) = split_i64(i64::MIN);
+    let (b1, b0) = split_i64(-1);
+    build_test!(source, &[a0, a1, b0, b1])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    #[test]
+    fn overflowing_add_proptest(a in any::<i64>(), b in any::<i64>()) {
+        let source = "
+            use.std::math::i64
+            begin
+                exec.i64::overflowing_add
+            end";
+
+        let (c, overflow) = a.overflowing_add(b);
+        let (a1, a0) = split_i64(a);
+        let (b1, b0) = split_i64(b);
+        let (c1, c0) = split_i64(c);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[overflow as u64, c1, c0])?;
+    }
+
+    #[test]
+    fn overflowing_sub_proptest(a in any::<i64>(), b in any::<i64>()) {
+        let source = "
+            use.std::math::i64
+            begin
+                exec.i64::overflowing_sub
+            end";
+
+        let (c, overflow) = a.overflowing_sub(b);
+        let (a1, a0) = split_i64(a);
+        let (b1, b0) = split_i64(b);
+        let (c1, c0) = split_i64(c);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[overflow as u64, c1, c0])?;
+    }
+
+    #[test]
+    fn overflowing_mul_proptest(a in any::<i64>(), b in any::<i64>()) {
+        let source = "
+            use.std::math::i64
+            begin
+                exec.i64::overflowing_mul
+            end";
+
+        let (c, overflow) = a.overflowing_mul(b);
+        let (a1, a0) = split_i64(a);
+        let (b1, b0) = split_i64(b);
+        let (c1, c0) = split_i64(c);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[overflow as u64, c1, c0])?;
+    }
+
+    #[test]
+    fn checked_divmod_proptest(a in any::<i64>(), b in any::<i64>()) {
+        prop_assume!(b != 0 && !(a == i64::MIN && b == -1));
+        let source = "
+            use.std::math::i64
+            begin
+                exec.i64::checked_divmod
+            end";
+
+        let (a1, a0) = split_i64(a);
+        let (b1, b0) = split_i64(b);
+        let (q1, q0) = split_i64(a / b);
+        let (r1, r0) = split_i64(a % b);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[r1, r0, q1, q0])?;
+    }
+
+    #[test]
+    fn comparisons_proptest(a in any::<i64>(), b in any::<i64>()) {
+        let source = "
+            use.std::math::i64
+            begin
+                dupw exec.i64::lt movdn.4
+                dupw exec.i64::lte movdn.4
+                dupw exec.i64::gt movdn.4
+                dupw exec.i64::gte movdn.4
+                dupw exec.i64::min movdn.5 movdn.5
+                exec.i64::max
+            end";
+
+        let (a1, a0) = split_i64(a);
+        let (b1, b0) = split_i64(b);
+        let (max1, max0) = split_i64(a.max(b));
+        let (min1, min0) = split_i64(a.min(b));
+        let expected = [
+            max1,
+            max0,
+            min1,
+            min0,
+            (a >= b) as u64,
+            (a > b) as u64,
+            (a <= b) as u64,
+            (a < b) as u64,
+        ];
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&expected)?;
+    }
+
+    #[test]
+    fn shr_proptest(a in any::<i64>(), b in 0_u32..64) {
+        let source = "
+            use.std::math::i64
+            begin
+                exec.i64::shr
+            end";
+
+        let (a1, a0) = split_i64(a);
+        let (c1, c0) = split_i64(a >> b);
+        build_test!(source, &[5, a0, a1, b as u64]).prop_expect_stack(&[c1, c0, 5])?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Splits the two's complement representation of the provided value into 32 high and low bits.
+fn split_i64(value: i64) -> (u64, u64) {
+    let value = value as u64;
+    (value >> 32, value as u32 as u64)
+}
@@ -1,6 +1,101 @@
 use crate::build_test;
 use num_bigint::BigUint;
-use test_utils::rand::rand_vector;
+use test_utils::{
+    rand::{rand_value, rand_vector},
+    TestError,
+};
+
+// ADDITION AND SUBTRACTION
+// ================================================================================================
+
+#[test]
+fn overflowing_add() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_add
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = &a + &b;
+    let overflow = (c.bits() > 256) as u64;
+
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&result(overflow, &c));
+}
+
+#[test]
+fn checked_add_fail() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_add
+        end";
+
+    let a = max_u256();
+    let b = BigUint::from(1_u32);
+    build_test!(source, &operands(&[&a, &b]))
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let mut stack = operands(&[&b, &b]);
+    stack[3] = u32::MAX as u64 + 1;
+    build_test!(source, &stack).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn overflowing_sub() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_sub
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let (c, underflow) = if a >= b {
+        (&a - &b, 0)
+    } else {
+        ((BigUint::from(1_u32) << 256) + &a - &b, 1)
+    };
+
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&result(underflow, &c));
+    build_test!(source, &operands(&[&a, &a])).expect_stack(&result(0, &BigUint::default()));
+}
+
+#[test]
+fn checked_sub_fail() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_sub
+        end";
+
+    let a = BigUint::from(1_u32) << 128;
+    let b = &a + 1_u32;
+    build_test!(source, &operands(&[&a, &b]))
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    let a = rand_u256();
+    let b = rand_u256();
+    for (a, b) in [(&a, &b), (&b, &a), (&a, &a)] {
+        for (proc, expected) in [("lt", a < b), ("lte", a <= b), ("gt", a > b), ("gte", a >= b)] {
+            let source = format!(
+                "
+                use.std::math::u256
+                begin
+                    exec.u256::{proc}_unsafe
+                end"
+            );
+            build_test!(&source, &operands(&[a, b])).expect_stack(&[expected as u64]);
+        }
+    }
+}
 
 // MULTIPLICATION
 // ================================================================================================
@@ -33,6 +128,160 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+#[test]
+fn overflowing_mul() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_mul
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = (&a * &b) % modulus();
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&result(1, &c));
+
+    // the product fits into 256 bits
+    let a = BigUint::from(rand_value::<u64>()) << 100;
+    let b = BigUint::from(rand_value::<u64>()) << 20;
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&result(0, &(&a * &b)));
+}
+
+#[test]
+fn checked_mul_fail() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_mul
+        end";
+
+    let a = BigUint::from(1_u32) << 128;
+    build_test!(source, &operands(&[&a, &a]))
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn divmod_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256() >> (rand_value::<u64>() % 256);
+    let b = if b == BigUint::default() {
+        BigUint::from(3_u32)
+    } else {
+        b
+    };
+    let mut expected = u256_to_stack(&(&a % &b));
+    expected.extend(u256_to_stack(&(&a / &b)));
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&expected);
+
+    let b = BigUint::default();
+    build_test!(source, &operands(&[&a, &b]))
+        .expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn div_and_mod_unsafe() {
+    let div = "
+        use.std::math::u256
+        begin
+            exec.u256::div_unsafe
+        end";
+    let modulo = "
+        use.std::math::u256
+        begin
+            exec.u256::mod_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = BigUint::from(rand_value::<u64>() | 1);
+    build_test!(div, &operands(&[&a, &b])).expect_stack(&u256_to_stack(&(&a / &b)));
+    build_test!(modulo, &operands(&[&a, &b])).expect_stack(&u256_to_stack(&(&a % &b)));
+}
+
+// SHIFTS
+// ================================================================================================
+
+#[test]
+fn shl_and_shr_unsafe() {
+    let shl = "
+        use.std::math::u256
+        begin
+            exec.u256::shl_unsafe
+        end";
+    let shr = "
+        use.std::math::u256
+        begin
+            exec.u256::shr_unsafe
+        end";
+
+    let a = rand_u256();
+    for shift in [0, 1, 31, 32, 33, 100, 224, 255, rand_value::<u64>() % 256] {
+        let mut stack = operands(&[&a]);
+        stack.push(shift);
+
+        let c = (&a << shift) % modulus();
+        build_test!(shl, &stack).expect_stack(&u256_to_stack(&c));
+
+        let c = &a >> shift;
+        build_test!(shr, &stack).expect_stack(&u256_to_stack(&c));
+    }
+
+    let mut stack = operands(&[&a]);
+    stack.push(256);
+    build_test!(shl, &stack).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// MODULAR ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn addmod_and_mulmod_unsafe() {
+    let addmod = "
+        use.std::math::u256
+        begin
+            exec.u256::addmod_unsafe
+        end";
+    let mulmod = "
+        use.std::math::u256
+        begin
+            exec.u256::mulmod_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let n = rand_u256() >> (rand_value::<u64>() % 128);
+    let stack = operands(&[&a, &b, &n]);
+
+    build_test!(addmod, &stack).expect_stack(&u256_to_stack(&((&a + &b) % &n)));
+    build_test!(mulmod, &stack).expect_stack(&u256_to_stack(&((&a * &b) % &n)));
+}
+
+#[test]
+fn exp_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::exp_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = a.modpow(&b, &modulus());
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&u256_to_stack(&c));
+
+    let b = BigUint::from(13_u32);
+    let c = a.modpow(&b, &modulus());
+    build_test!(source, &operands(&[&a, &b])).expect_stack(&u256_to_stack(&c));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -40,3 +289,35 @@ fn rand_u256() -> BigUint {
     let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
     BigUint::new(limbs)
 }
+
+fn max_u256() -> BigUint {
+    modulus() - 1_u32
+}
+
+fn modulus() -> BigUint {
+    BigUint::from(1_u32) << 256
+}
+
+/// Returns the 32-bit limbs of the provided value with the least significant limb first.
+fn u256_to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns the stack inputs for the provided values, such that the last value ends up on top of
+/// the stack with its most significant limb first.
+fn operands(values: &[&BigUint]) -> Vec<u64> {
+    values.iter().flat_map(|v| u256_to_limbs(v)).collect()
+}
+
+/// Returns the limbs of the provided value as they are expected to appear on the stack.
+fn u256_to_stack(value: &BigUint) -> Vec<u64> {
+    u256_to_limbs(value).into_iter().rev().collect()
+}
+
+fn result(flag: u64, value: &BigUint) -> Vec<u64> {
+    let mut result = vec![flag];
+    result.extend(u256_to_stack(value));
+    result
+}
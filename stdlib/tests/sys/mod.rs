@@ -1,5 +1,8 @@
 use crate::build_test;
-use test_utils::{proptest::prelude::*, rand::rand_vector, STACK_TOP_SIZE};
+use test_utils::{
+    build_expected_hash, hash_elements, proptest::prelude::*, rand::rand_vector, stack_to_ints,
+    Felt, STACK_TOP_SIZE,
+};
 
 #[test]
 fn truncate_stack() {
@@ -8,6 +11,47 @@ fn truncate_stack() {
     test.expect_stack(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
 }
 
+#[test]
+fn commit_outputs() {
+    // an even and an odd number of words
+    for num_words in [2_u64, 3] {
+        let data = (1..=num_words * 4).collect::<Vec<_>>();
+        let store_data = data
+            .chunks(4)
+            .zip(1000..)
+            .map(|(word, addr)| {
+                format!(
+                    "push.{}.{}.{}.{} push.{addr} mem_storew dropw",
+                    word[0], word[1], word[2], word[3]
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let source = format!(
+            "
+            use.std::sys
+
+            begin
+                {store_data}
+                push.{} push.1000
+                exec.sys::commit_outputs
+            end",
+            1000 + num_words
+        );
+
+        // the commitment is left on the stack
+        let expected_hash = build_expected_hash(&data);
+        build_test!(&source).expect_stack(&stack_to_ints(&expected_hash));
+
+        // and the data is copied into the advice map under the commitment
+        let process = build_test!(&source).execute_process().unwrap();
+        let host = process.host.borrow_mut();
+        let key = hash_elements(&data.iter().map(|&v| Felt::new(v)).collect::<Vec<_>>());
+        let advice_data = host.advice_provider().map().get(&key.as_bytes()).unwrap();
+        assert_eq!(stack_to_ints(advice_data), data);
+    }
+}
+
 proptest! {
     #[test]
     fn truncate_stack_proptest(test_values in prop::collection::vec(any::<u64>(), STACK_TOP_SIZE), n in 1_usize..100) {
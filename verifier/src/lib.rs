@@ -2,9 +2,12 @@
 
 use air::{HashFunction, ProcessorAir, PublicInputs};
 use core::fmt;
-use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256},
-    random::{RpoRandomCoin, WinterRandomCoin},
+use vm_core::{
+    crypto::{
+        hash::{Blake3_192, Blake3_256, Rpo256},
+        random::{RpoRandomCoin, WinterRandomCoin},
+    },
+    Felt, WORD_SIZE,
 };
 use winter_verifier::verify as verify_proof;
 
@@ -16,7 +19,7 @@ pub use winter_verifier::VerifierError;
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, ProofBundle};
 
 // VERIFIER
 // ================================================================================================
@@ -66,6 +69,45 @@ pub fn verify(
     Ok(security_level)
 }
 
+/// Returns the security level of the proof contained in the specified bundle if the program
+/// specified by the bundle was executed correctly against the inputs and outputs in the bundle.
+///
+/// The bundle specifies the program whose execution it proves. Thus, a successful verification
+/// only attests to the execution of the program with hash `bundle.program_info().program_hash()`,
+/// and the caller is responsible for checking that this is the expected program.
+///
+/// # Errors
+/// Returns an error if the proof in the bundle does not prove a correct execution of the program.
+pub fn verify_bundle(bundle: ProofBundle) -> Result<u32, VerificationError> {
+    let (program_info, stack_inputs, stack_outputs, _, proof) = bundle.into_parts();
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Verifies that the specified data is the data committed to by a program as its public outputs.
+///
+/// Programs which output more data than fits onto the stack can write the data into a memory
+/// region and commit to it using `std::sys::commit_outputs` procedure; this leaves the RPO hash
+/// of the data at the top of the stack. The data itself can be retrieved from the advice map of
+/// the host which executed the program. Once the proof of execution has been verified against
+/// `stack_outputs`, this function can be used to check that the data matches the commitment in
+/// the first word of `stack_outputs`.
+///
+/// # Errors
+/// Returns an error if the length of the data is not a multiple of the word size, if
+/// `stack_outputs` do not contain a word, or if the hash of the data is not equal to the first
+/// word of `stack_outputs`.
+pub fn verify_output_data(
+    stack_outputs: &StackOutputs,
+    data: &[Felt],
+) -> Result<(), VerificationError> {
+    let commitment = stack_outputs.get_stack_word(0).map(Digest::from);
+    let is_word_aligned = !data.is_empty() && data.len() % WORD_SIZE == 0;
+    match commitment {
+        Some(commitment) if is_word_aligned && Rpo256::hash_elements(data) == commitment => Ok(()),
+        _ => Err(VerificationError::OutputCommitmentMismatch),
+    }
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -75,6 +117,7 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    OutputCommitmentMismatch,
 }
 
 impl fmt::Display for VerificationError {
@@ -84,6 +127,9 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            OutputCommitmentMismatch => {
+                write!(f, "the output data does not match the output commitment")
+            }
         }
     }
 }